@@ -19,6 +19,14 @@ FIXTURES = [
     ("tests/fixtures/plain.md", "tests/fixtures/plain.txt", ["--plain"]),
     ("tests/fixtures/images.md", "tests/fixtures/images.txt", []),
     ("tests/fixtures/json.md", "tests/fixtures/json.txt", ["--format", "json"]),
+    ("tests/fixtures/tables.md", "tests/fixtures/tables.txt", []),
+    ("tests/fixtures/tables.md", "tests/fixtures/tables_key_value.txt", ["--tables", "key-value"]),
+    ("tests/fixtures/footnotes.md", "tests/fixtures/footnotes.txt", []),
+    ("tests/fixtures/callouts.md", "tests/fixtures/callouts.txt", []),
+    ("tests/fixtures/smart_punctuation.md", "tests/fixtures/smart_punctuation.txt", ["--code-blocks", "text"]),
+    ("tests/fixtures/smart_punctuation.md", "tests/fixtures/smart_punctuation_fr.txt", ["--code-blocks", "text", "--locale", "fr"]),
+    ("tests/fixtures/smart_punctuation.md", "tests/fixtures/smart_punctuation_de.txt", ["--code-blocks", "text", "--locale", "de"]),
+    ("tests/fixtures/smart_punctuation.md", "tests/fixtures/smart_punctuation_ja.txt", ["--code-blocks", "text", "--locale", "ja"]),
 ]
 
 
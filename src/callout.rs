@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+/// Obsidian callout aliases and the kind they share an icon with.
+const ALIASES: &[(&str, &str)] = &[
+    ("summary", "abstract"),
+    ("tldr", "abstract"),
+    ("hint", "tip"),
+    ("check", "success"),
+    ("done", "success"),
+    ("help", "question"),
+    ("faq", "question"),
+    ("attention", "warning"),
+    ("fail", "failure"),
+    ("missing", "failure"),
+    ("error", "danger"),
+    ("cite", "quote"),
+];
+
+/// Icons for the GitHub alert and Obsidian callout kinds.
+pub fn default_callout_icons() -> HashMap<String, String> {
+    [
+        ("note", "📝"),
+        ("abstract", "📋"),
+        ("info", "ℹ️"),
+        ("todo", "☑️"),
+        ("tip", "💡"),
+        ("important", "❗"),
+        ("success", "✅"),
+        ("question", "❓"),
+        ("warning", "⚠️"),
+        ("caution", "🛑"),
+        ("failure", "❌"),
+        ("danger", "🚨"),
+        ("bug", "🐛"),
+        ("example", "🧪"),
+        ("quote", "💬"),
+    ]
+    .into_iter()
+    .map(|(kind, icon)| (kind.to_string(), icon.to_string()))
+    .collect()
+}
+
+/// Lowercased kind with Obsidian aliases resolved, e.g. `FAQ` → `question`.
+pub fn canonical_kind(kind: &str) -> String {
+    let kind = kind.to_ascii_lowercase();
+    ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == kind)
+        .map(|(_, canonical)| canonical.to_string())
+        .unwrap_or(kind)
+}
+
+/// A `[!KIND]` marker at the start of a blockquote.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalloutMarker<'a> {
+    /// The kind as written, e.g. `NOTE` or `faq`.
+    pub kind: &'a str,
+    /// Text after the marker on the same line, used as the title.
+    pub title: &'a str,
+}
+
+impl CalloutMarker<'_> {
+    /// Label shown when there is no title: the kind as a capitalized word.
+    pub fn label(&self) -> String {
+        let lower = self.kind.to_ascii_lowercase();
+        let mut chars = lower.chars();
+        match chars.next() {
+            Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+            None => String::new(),
+        }
+    }
+}
+
+/// Parse `[!KIND]`, `[!KIND]+` or `[!KIND]- Title` at the start of `text`.
+///
+/// # Example
+/// ```
+/// use mdtolinkedin::callout::parse_marker;
+/// let marker = parse_marker("[!TIP] Ship it").unwrap();
+/// assert_eq!((marker.kind, marker.title), ("TIP", "Ship it"));
+/// ```
+pub fn parse_marker(text: &str) -> Option<CalloutMarker<'_>> {
+    let rest = text.strip_prefix("[!")?;
+    let end = rest.find(']')?;
+    let kind = &rest[..end];
+    if kind.is_empty() || !kind.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return None;
+    }
+    let title = rest[end + 1..].trim_start_matches(['+', '-']);
+    if !title.is_empty() && !title.starts_with(char::is_whitespace) {
+        return None;
+    }
+    Some(CalloutMarker {
+        kind,
+        title: title.trim_start(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_gfm_alert() {
+        let marker = parse_marker("[!WARNING]").unwrap();
+        assert_eq!(marker.kind, "WARNING");
+        assert_eq!(marker.title, "");
+        assert_eq!(marker.label(), "Warning");
+    }
+
+    #[test]
+    fn test_parse_foldable_callout() {
+        let marker = parse_marker("[!faq]- Why not?").unwrap();
+        assert_eq!(marker.kind, "faq");
+        assert_eq!(marker.title, "Why not?");
+    }
+
+    #[test]
+    fn test_not_a_marker() {
+        assert!(parse_marker("[!NOTE]: trailing").is_none());
+        assert!(parse_marker("[link] text").is_none());
+        assert!(parse_marker("[!] empty").is_none());
+    }
+
+    #[test]
+    fn test_canonical_kind() {
+        assert_eq!(canonical_kind("FAQ"), "question");
+        assert_eq!(canonical_kind("Tip"), "tip");
+        assert_eq!(canonical_kind("custom"), "custom");
+    }
+}
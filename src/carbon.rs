@@ -13,7 +13,7 @@ pub fn carbon_url(code: &str, language: Option<&str>) -> String {
     url
 }
 
-fn percent_encode(input: &str) -> String {
+pub(crate) fn percent_encode(input: &str) -> String {
     let mut out = String::new();
 
     for b in input.as_bytes() {
@@ -1,6 +1,11 @@
 use clap::{Parser, ValueEnum};
+use pulldown_cmark::HeadingLevel;
 use std::path::PathBuf;
 
+use crate::callout::canonical_kind;
+use crate::heading::{parse_level, HeadingStyle};
+use crate::unicode::StyleFamily;
+
 #[derive(Copy, Clone, Debug, ValueEnum)]
 pub enum CodeBlocksArg {
     Omit,
@@ -9,6 +14,78 @@ pub enum CodeBlocksArg {
     Image,
 }
 
+#[derive(Copy, Clone, Debug, ValueEnum)]
+pub enum TablesArg {
+    Grid,
+    KeyValue,
+    Bullets,
+}
+
+#[derive(Copy, Clone, Debug, ValueEnum)]
+pub enum TablePaddingArg {
+    Monospace,
+    Figure,
+}
+
+#[derive(Copy, Clone, Debug, ValueEnum)]
+pub enum TaskStyleArg {
+    Ballot,
+    Emoji,
+    Check,
+}
+
+#[derive(Copy, Clone, Debug, ValueEnum)]
+pub enum FootnoteHeadingArg {
+    Bold,
+    Italic,
+    Plain,
+}
+
+#[derive(Copy, Clone, Debug, ValueEnum)]
+pub enum RuleArg {
+    Line,
+    Dots,
+    Wave,
+    Blank,
+}
+
+#[derive(Copy, Clone, Debug, ValueEnum)]
+pub enum DefinitionsArg {
+    Dash,
+    Indent,
+}
+
+#[derive(Copy, Clone, Debug, ValueEnum)]
+pub enum LinksArg {
+    Inline,
+    References,
+    Domain,
+    Text,
+    Url,
+}
+
+#[derive(Copy, Clone, Debug, ValueEnum)]
+pub enum LocaleArg {
+    En,
+    Fr,
+    De,
+    Ja,
+}
+
+#[derive(Copy, Clone, Debug, ValueEnum)]
+pub enum InlineCodeArg {
+    Monospace,
+    Backticks,
+    Guillemets,
+}
+
+#[derive(Copy, Clone, Debug, ValueEnum)]
+pub enum UnstyledFallbackArg {
+    Keep,
+    Underline,
+    Brackets,
+}
+
 #[derive(Copy, Clone, Debug, ValueEnum, PartialEq, Eq)]
 pub enum OutputFormat {
     Text,
@@ -34,7 +111,7 @@ pub struct Cli {
     #[arg(long, value_enum, value_name = "MODE")]
     pub code_blocks: Option<CodeBlocksArg>,
 
-    /// Suppress character limit warning
+    /// Suppress warnings (character limit, unconverted markup)
     #[arg(long)]
     pub no_warn: bool,
 
@@ -42,6 +119,14 @@ pub struct Cli {
     #[arg(long, value_name = "N")]
     pub max_chars: Option<usize>,
 
+    /// Custom character limit for the first comment
+    #[arg(long, value_name = "N")]
+    pub comment_max_chars: Option<usize>,
+
+    /// Move external links into the first comment, keeping their text in the post
+    #[arg(long)]
+    pub links_in_comment: bool,
+
     /// Custom bullet character for unordered lists
     #[arg(long, value_name = "CHAR")]
     pub bullet: Option<String>,
@@ -54,6 +139,122 @@ pub struct Cli {
     #[arg(long)]
     pub plain: bool,
 
+    /// Table rendering: grid, key-value, or bullets
+    #[arg(long, value_enum, value_name = "MODE", default_value_t = TablesArg::Grid)]
+    pub tables: TablesArg,
+
+    /// Padding used to align grid table columns: monospace or figure spaces
+    #[arg(long, value_enum, value_name = "KIND", default_value_t = TablePaddingArg::Monospace)]
+    pub table_padding: TablePaddingArg,
+
+    /// Task list glyphs: ballot (☑/☐), emoji (✅/⬜), or check (✔/✖)
+    #[arg(long, value_enum, value_name = "STYLE", default_value_t = TaskStyleArg::Ballot)]
+    pub task_style: TaskStyleArg,
+
+    /// Heading for the footnotes block (empty to omit)
+    #[arg(long, value_name = "TEXT", default_value = "Notes")]
+    pub footnote_heading: String,
+
+    /// Footnotes heading style: bold, italic, or plain
+    #[arg(long, value_enum, value_name = "STYLE", default_value_t = FootnoteHeadingArg::Bold)]
+    pub footnote_heading_style: FootnoteHeadingArg,
+
+    /// Horizontal rule separator: line (━━━), dots (· · ·), wave (〰️), or blank
+    #[arg(long, value_enum, value_name = "STYLE", default_value_t = RuleArg::Line)]
+    pub rule: RuleArg,
+
+    /// Custom horizontal rule separator text (overrides --rule)
+    #[arg(long, value_name = "TEXT")]
+    pub rule_text: Option<String>,
+
+    /// Heading style per level, e.g. "h1=bold,upper,prefix=🚀,underline=━" (repeatable)
+    #[arg(long, value_name = "LEVEL=SPEC", value_parser = parse_heading_style)]
+    pub heading_style: Vec<(HeadingLevel, HeadingStyle)>,
+
+    /// Inline code rendering: monospace, backticks, or guillemets (‹›)
+    #[arg(long, value_enum, value_name = "STYLE", default_value_t = InlineCodeArg::Monospace)]
+    pub inline_code: InlineCodeArg,
+
+    /// Leave digits unstyled inside bold and bold-italic text
+    #[arg(long)]
+    pub no_styled_digits: bool,
+
+    /// Styled letters with no Unicode equivalent (Cyrillic, CJK): keep, underline, or brackets
+    #[arg(long, value_enum, value_name = "MODE", default_value_t = UnstyledFallbackArg::Keep)]
+    pub unstyled_fallback: UnstyledFallbackArg,
+
+    /// Enable {family}[text] and ==highlight== inline spans
+    #[arg(long)]
+    pub inline_spans: bool,
+
+    /// Style family for ==highlight== spans (with --inline-spans)
+    #[arg(long, value_name = "FAMILY", default_value = "sans-bold", value_parser = parse_family)]
+    pub highlight_style: StyleFamily,
+
+    /// Read single-tilde ~text~ as subscript (H~2~O) instead of strikethrough
+    #[arg(long)]
+    pub subscript: bool,
+
+    /// Convert $...$ and $$...$$ LaTeX math to Unicode (off: dollar signs stay as written)
+    #[arg(long)]
+    pub math: bool,
+
+    /// Keep straight quotes, -- and ... as written
+    #[arg(long)]
+    pub no_smart_punctuation: bool,
+
+    /// Quotation marks: en (“”), fr («»), de („“), or ja (「」)
+    #[arg(long, value_enum, value_name = "LOCALE", default_value_t = LocaleArg::En)]
+    pub locale: LocaleArg,
+
+    /// Leave :shortcode: emoji as written
+    #[arg(long)]
+    pub no_emoji: bool,
+
+    /// Extra emoji shortcodes from a file of `name = emoji` lines
+    #[arg(long, value_name = "FILE")]
+    pub emoji_map: Option<PathBuf>,
+
+    /// Definition list layout: dash (– definition) or indent
+    #[arg(long, value_enum, value_name = "LAYOUT", default_value_t = DefinitionsArg::Dash)]
+    pub definitions: DefinitionsArg,
+
+    /// Style family for definition list terms
+    #[arg(long, value_name = "FAMILY", default_value = "bold", value_parser = parse_family)]
+    pub definition_style: StyleFamily,
+
+    /// Link rendering: inline (text (url)), references (text [1] … [1] url), domain, text, or url
+    #[arg(long, value_enum, value_name = "STYLE", default_value_t = LinksArg::Inline)]
+    pub links: LinksArg,
+
+    /// Keep tracking parameters, host case and ports in link URLs as written
+    #[arg(long)]
+    pub no_clean_urls: bool,
+
+    /// UTM parameter added to link URLs, e.g. source=linkedin or campaign={slug} (repeatable)
+    #[arg(long, value_name = "KEY=VALUE", value_parser = parse_utm)]
+    pub utm: Vec<(String, String)>,
+
+    /// Only add UTM parameters to links on this domain or its subdomains (repeatable)
+    #[arg(long, value_name = "DOMAIN")]
+    pub url_allow: Vec<String>,
+
+    /// Never rewrite links on this domain or its subdomains (repeatable)
+    #[arg(long, value_name = "DOMAIN")]
+    pub url_deny: Vec<String>,
+
+    /// URL template for [[wikilinks]], with {name} and {slug} placeholders
+    #[arg(long, value_name = "TEMPLATE")]
+    pub wikilink_url: Option<String>,
+
+    /// Wikilink targets from a file of `Note name = https://...` lines
+    #[arg(long, value_name = "FILE")]
+    pub wikilink_map: Option<PathBuf>,
+
+    /// Icon for a `> [!KIND]` alert or callout, e.g. tip=🔥 (repeatable)
+    #[arg(long, value_name = "KIND=EMOJI", value_parser = parse_callout_icon)]
+    pub callout_icon: Vec<(String, String)>,
+
     /// Output format: text or json
     #[arg(long, value_enum, value_name = "FORMAT", default_value_t = OutputFormat::Text)]
     pub format: OutputFormat,
@@ -82,3 +283,35 @@ pub struct Cli {
     #[arg(long, value_name = "PX", default_value_t = 24)]
     pub code_image_padding: u32,
 }
+
+fn parse_heading_style(value: &str) -> Result<(HeadingLevel, HeadingStyle), String> {
+    let (level, spec) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected LEVEL=SPEC, got {}", value))?;
+    let level = parse_level(level).ok_or_else(|| format!("unknown heading level: {}", level))?;
+    Ok((level, HeadingStyle::parse(spec)?))
+}
+
+fn parse_callout_icon(value: &str) -> Result<(String, String), String> {
+    let (kind, icon) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected KIND=EMOJI, got {}", value))?;
+    Ok((canonical_kind(kind.trim()), icon.trim().to_string()))
+}
+
+fn parse_utm(value: &str) -> Result<(String, String), String> {
+    let (key, value) = value
+        .split_once('=')
+        .filter(|(key, _)| !key.trim().is_empty())
+        .ok_or_else(|| format!("expected KEY=VALUE, got {}", value))?;
+    let key = key.trim();
+    let key = match key.starts_with("utm_") {
+        true => key.to_string(),
+        false => format!("utm_{}", key),
+    };
+    Ok((key, value.trim().to_string()))
+}
+
+fn parse_family(value: &str) -> Result<StyleFamily, String> {
+    StyleFamily::from_name(value).ok_or_else(|| format!("unknown style family: {}", value))
+}
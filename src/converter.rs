@@ -1,7 +1,29 @@
+use crate::callout::{self, default_callout_icons};
 use crate::carbon::carbon_url;
 use crate::code_image::{render_code_image, CodeImageOptions};
-use crate::unicode::{to_bold, to_bold_italic, to_italic};
-use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag, TagEnd};
+use crate::emoji;
+use crate::entities::{self, Segment};
+use crate::first_comment;
+use crate::footnotes::{self, FootnoteHeadingStyle, Footnotes};
+use crate::front_matter;
+use crate::heading::{default_heading_styles, HeadingStyle};
+use crate::html::{self, HtmlScanner, HtmlToken};
+use crate::links::{self, LinkReferences, LinkStyle};
+use crate::math;
+use crate::punctuation::{self, QuoteStyle};
+use crate::spans::{self, ScriptRun, SpanToken};
+use crate::table::{Table, TableMode, TablePadding};
+use crate::unicode::{
+    display_width, to_monospace, to_strikethrough, to_subscript, to_superscript, to_underline,
+    StyleFamily, UnstyledFallback,
+};
+use crate::urls::{self, UrlRewrite};
+use crate::wikilink;
+use pulldown_cmark::{
+    CodeBlockKind, Event, HeadingLevel, LinkType, Options, Parser, Tag, TagEnd, TextMergeStream,
+};
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fmt::Write;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -10,6 +32,54 @@ enum TextStyle {
     Bold,
     Italic,
     BoldItalic,
+    Strikethrough,
+    Underline,
+    Superscript,
+    Subscript,
+    Family(StyleFamily),
+}
+
+impl From<StyleFamily> for TextStyle {
+    fn from(family: StyleFamily) -> Self {
+        match family {
+            StyleFamily::Normal => TextStyle::Normal,
+            StyleFamily::Bold => TextStyle::Bold,
+            StyleFamily::Italic => TextStyle::Italic,
+            StyleFamily::BoldItalic => TextStyle::BoldItalic,
+            other => TextStyle::Family(other),
+        }
+    }
+}
+
+impl TextStyle {
+    /// Decorations are layered on top of the letter style rather than replacing it.
+    fn is_decoration(self) -> bool {
+        matches!(
+            self,
+            TextStyle::Strikethrough
+                | TextStyle::Underline
+                | TextStyle::Superscript
+                | TextStyle::Subscript
+        )
+    }
+
+    /// The style `**strong**` or `<b>` switches to from this one.
+    fn bolder(self) -> Self {
+        match self {
+            TextStyle::Italic => TextStyle::BoldItalic,
+            TextStyle::Family(family) => TextStyle::from(family.bolder()),
+            _ => TextStyle::Bold,
+        }
+    }
+
+    /// The style `*emphasis*` or `<i>` switches to from this one.
+    fn italicized(self) -> Self {
+        match self {
+            TextStyle::Bold => TextStyle::BoldItalic,
+            TextStyle::Family(family) => TextStyle::from(family.italicized()),
+            _ => TextStyle::Italic,
+        }
+    }
 }
 
 pub struct ConvertOptions {
@@ -18,6 +88,48 @@ pub struct ConvertOptions {
     pub trim_output: bool,
     pub plain: bool,
     pub code_image: Option<CodeImageOptions>,
+    pub table_mode: TableMode,
+    pub table_padding: TablePadding,
+    pub task_list_style: TaskListStyle,
+    pub footnote_heading: String,
+    pub footnote_heading_style: FootnoteHeadingStyle,
+    pub rule: RuleStyle,
+    pub heading_styles: HashMap<HeadingLevel, HeadingStyle>,
+    pub inline_code: InlineCodeStyle,
+    /// Map digits to the matching Mathematical digits inside styled runs.
+    pub style_digits: bool,
+    /// How to mark letters a style has no Unicode equivalent for (e.g. Cyrillic).
+    pub unstyled_fallback: UnstyledFallback,
+    /// Enable `{family}[text]` and `==highlight==` inline spans.
+    pub inline_spans: bool,
+    pub highlight_family: StyleFamily,
+    /// Expand `:shortcode:` emoji outside of code.
+    pub emoji_shortcodes: bool,
+    /// Extra or overriding shortcodes, e.g. from `--emoji-map`.
+    pub emoji_map: HashMap<String, String>,
+    /// Icon for each `> [!KIND]` alert or callout, keyed by `callout::canonical_kind`.
+    pub callout_icons: HashMap<String, String>,
+    /// Whether definitions go on `– ` lines or are indented under the term.
+    pub definition_layout: DefinitionLayout,
+    /// Style family for definition list terms.
+    pub definition_term_family: StyleFamily,
+    /// URL template for `[[Note]]` links, with `{name}` and `{slug}` placeholders.
+    pub wikilink_template: Option<String>,
+    /// Note name → public URL, checked before the template.
+    pub wikilink_urls: HashMap<String, String>,
+    /// Curly quotes, en/em dashes and ellipses (code is never touched).
+    pub smart_punctuation: bool,
+    /// Read single-tilde `~text~` as subscript; otherwise it is GFM strikethrough.
+    pub subscript: bool,
+    /// Read `$...$` and `$$...$$` as LaTeX math; otherwise dollar signs stay text.
+    pub math: bool,
+    pub quote_style: QuoteStyle,
+    pub link_style: LinkStyle,
+    /// Tracking cleanup, UTM tags and domain rules for link and image URLs.
+    pub url_rewrite: UrlRewrite,
+    /// Show external links as text in the post and list their URLs in the
+    /// first comment.
+    pub links_in_comment: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -28,12 +140,140 @@ pub enum CodeBlockMode {
     Image,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskListStyle {
+    /// ☑ / ☐
+    Ballot,
+    /// ✅ / ⬜
+    Emoji,
+    /// ✔ / ✖
+    Check,
+}
+
+impl TaskListStyle {
+    /// Glyph used for a checked or unchecked task item.
+    pub fn glyph(self, checked: bool) -> &'static str {
+        match (self, checked) {
+            (TaskListStyle::Ballot, true) => "☑",
+            (TaskListStyle::Ballot, false) => "☐",
+            (TaskListStyle::Emoji, true) => "✅",
+            (TaskListStyle::Emoji, false) => "⬜",
+            (TaskListStyle::Check, true) => "✔",
+            (TaskListStyle::Check, false) => "✖",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InlineCodeStyle {
+    /// Mathematical Monospace letters and digits (raw text in plain mode)
+    Monospace,
+    /// `code`
+    Backticks,
+    /// ‹code›
+    Guillemets,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleStyle {
+    /// ━━━━━━━━
+    Line,
+    /// · · ·
+    Dots,
+    /// 〰️
+    Wave,
+    /// An extra empty line between sections
+    Blank,
+    Custom(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefinitionLayout {
+    /// – definition
+    Dash,
+    /// Definition indented under the term
+    Indent,
+}
+
+impl DefinitionLayout {
+    /// Text placed before each definition line.
+    pub fn prefix(self) -> &'static str {
+        match self {
+            DefinitionLayout::Dash => "– ",
+            DefinitionLayout::Indent => "    ",
+        }
+    }
+}
+
+impl RuleStyle {
+    /// Separator line for a thematic break (empty for `Blank`).
+    pub fn separator(&self) -> &str {
+        match self {
+            RuleStyle::Line => "━━━━━━━━",
+            RuleStyle::Dots => "· · ·",
+            RuleStyle::Wave => "〰\u{FE0F}",
+            RuleStyle::Blank => "",
+            RuleStyle::Custom(text) => text,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OpenSpan {
+    Bracket,
+    Highlight,
+}
+
+#[derive(Debug, Clone)]
+struct PendingLink {
+    url: String,
+    /// The URL as written in the Markdown, before `urls::rewrite`.
+    written_url: String,
+    text_start: usize,
+    /// Where the text of the enclosing block starts, after any bullet or prefix.
+    block_start: usize,
+    /// `<https://…>` or `<name@example.com>`, whose text already is the URL.
+    autolink: bool,
+    /// Marked as the link-preview URL with a `"preview"` title or `{.preview}`.
+    preview: bool,
+}
+
+/// Written right after a link to make it the preview link: `[text](url){.preview}`
+const PREVIEW_ATTRIBUTE: &str = "{.preview}";
+
+/// The same attribute read as a heading class, when the link ends a heading.
+const PREVIEW_CLASS: &str = "preview";
+
 #[derive(Debug, Clone)]
 struct ListContext {
     ordered: bool,
     next_index: u64,
 }
 
+/// Footnotes and `--links references` URLs of the post or of the first
+/// comment; each part numbers and lists its own.
+#[derive(Debug, Default)]
+struct PartNotes {
+    footnotes: Footnotes,
+    references: LinkReferences,
+}
+
+impl PartNotes {
+    /// Append the notes block, then the `[n] url` lines.
+    fn push_to(&self, output: &mut String, options: &ConvertOptions) {
+        let notes = self.footnotes.render(
+            &options.footnote_heading,
+            options.footnote_heading_style,
+            options.plain,
+        );
+        for block in [notes, self.references.render()].into_iter().flatten() {
+            ensure_blank_line(output);
+            output.push_str(&block);
+            ensure_blank_line(output);
+        }
+    }
+}
+
 impl Default for ConvertOptions {
     fn default() -> Self {
         Self {
@@ -42,57 +282,461 @@ impl Default for ConvertOptions {
             trim_output: true,
             plain: false,
             code_image: None,
+            table_mode: TableMode::Grid,
+            table_padding: TablePadding::Monospace,
+            task_list_style: TaskListStyle::Ballot,
+            footnote_heading: "Notes".to_string(),
+            footnote_heading_style: FootnoteHeadingStyle::Bold,
+            rule: RuleStyle::Line,
+            heading_styles: default_heading_styles(),
+            inline_code: InlineCodeStyle::Monospace,
+            style_digits: true,
+            unstyled_fallback: UnstyledFallback::Keep,
+            inline_spans: false,
+            highlight_family: StyleFamily::SansBold,
+            emoji_shortcodes: true,
+            emoji_map: HashMap::new(),
+            callout_icons: default_callout_icons(),
+            definition_layout: DefinitionLayout::Dash,
+            definition_term_family: StyleFamily::Bold,
+            wikilink_template: None,
+            wikilink_urls: HashMap::new(),
+            smart_punctuation: true,
+            subscript: false,
+            math: false,
+            quote_style: QuoteStyle::English,
+            link_style: LinkStyle::Inline,
+            url_rewrite: UrlRewrite::default(),
+            links_in_comment: false,
         }
     }
 }
 
+/// Converted text plus notes about markup that could not be carried over.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Conversion {
+    pub text: String,
+    pub warnings: Vec<String>,
+    /// URL of the link marked for LinkedIn's preview card, placed at the end of `text`.
+    pub preview_url: Option<String>,
+    /// Text after a `<!-- first-comment -->` marker or `## First comment`
+    /// heading, plus any links moved there by `links_in_comment`.
+    pub first_comment: Option<String>,
+}
+
 pub fn convert(markdown: &str, options: &ConvertOptions) -> String {
-    let parser = Parser::new(markdown);
+    convert_document(markdown, options).text
+}
+
+pub fn convert_document(markdown: &str, options: &ConvertOptions) -> Conversion {
+    let mut parser_options = Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_SUPERSCRIPT
+        | Options::ENABLE_DEFINITION_LIST
+        | Options::ENABLE_WIKILINKS
+        | Options::ENABLE_HEADING_ATTRIBUTES
+        | Options::ENABLE_YAML_STYLE_METADATA_BLOCKS;
+    if options.smart_punctuation {
+        parser_options |= Options::ENABLE_SMART_PUNCTUATION;
+    }
+    if options.subscript {
+        parser_options |= Options::ENABLE_SUBSCRIPT;
+    }
+    if options.math {
+        parser_options |= Options::ENABLE_MATH;
+    }
+    let parser = Parser::new_ext(markdown, parser_options);
     let mut output = String::with_capacity(markdown.len());
     let mut style_stack: Vec<TextStyle> = Vec::with_capacity(8);
     style_stack.push(TextStyle::Normal);
-    let mut pending_link_url: Option<String> = None;
+    // URL of the open link or image and where its text starts in `output`
+    let mut pending_link: Option<PendingLink> = None;
+    let mut block_start = 0;
+    // `# Title [text](url){.preview}` parses `{.preview}` as a heading class
+    let mut heading_preview = false;
+    let mut post_notes = PartNotes::default();
+    let mut comment_notes = PartNotes::default();
+    let mut ended_link: Option<PendingLink> = None;
+    let mut preview_url: Option<String> = None;
+    let mut comment_start: Option<usize> = None;
+    // (url, line) for links moved into the first comment
+    let mut comment_links: Vec<(String, String)> = Vec::new();
+    let mut heading_text = String::new();
+    let mut in_front_matter = false;
+    let mut front_matter: HashMap<String, String> = HashMap::new();
+    let mut utm_tags = options.url_rewrite.tags(&front_matter);
     let mut in_code_block = false;
     let mut code_block_content = String::with_capacity(256);
     let mut code_block_language: Option<String> = None;
     let mut code_block_index: usize = 0;
     let mut list_stack: Vec<ListContext> = Vec::with_capacity(8);
+    let mut bullet_start: Option<usize> = None;
+    let mut table: Option<Table> = None;
+    let mut table_row: Vec<String> = Vec::new();
+    let mut table_cell_start: usize = 0;
+    let mut footnote_definition: Option<(String, usize)> = None;
+    let fallback_heading = HeadingStyle::new(StyleFamily::Bold);
+    let mut current_heading: Option<(Cow<HeadingStyle>, usize)> = None;
+    let mut hidden_heading = false;
+    let mut heading_word_start = true;
+    // Style stack depth of each open Markdown emphasis-like tag
+    let mut markdown_styles: Vec<usize> = Vec::new();
+    let mut open_spans: Vec<(OpenSpan, usize)> = Vec::new();
+    let mut html_scanner = HtmlScanner::default();
+    let mut html_tags: Vec<(String, usize)> = Vec::new();
+    let mut in_html_block = false;
+    let mut after_inline_comment = false;
+    let mut unmapped_tags: Vec<String> = Vec::new();
+    let mut unsupported_math: Vec<String> = Vec::new();
+    let mut accidental_hashtags: Vec<String> = Vec::new();
+    let mut unresolved_wikilinks: Vec<String> = Vec::new();
+    let mut warnings: Vec<String> = Vec::new();
+    let mut pending_callout: Option<usize> = None;
+    let mut callout_title: Option<usize> = None;
+    // Label of a bare `[!KIND]` marker, shown unless a title follows on its line
+    let mut callout_label: Option<String> = None;
+    let mut definition_paragraphs: Option<usize> = None;
+    let mut single_quote_open = false;
 
-    for event in parser {
+    for event in TextMergeStream::new(literal_emoticons(parser).into_iter()) {
+        // An `<a href>` still open when its cell or block ends closes there,
+        // before the cell is split off or later blocks join its text
+        let unclosed_anchor = pending_link.take_if(|_| is_block_end(&event));
+        let mut event = event;
+        for mut link in ended_link.take().into_iter().chain(unclosed_anchor) {
+            if heading_preview && matches!(event, Event::End(TagEnd::Heading(_))) {
+                heading_preview = false;
+                link.preview = true;
+            }
+            if let Event::Text(text) = &event {
+                if let Some(rest) = text.strip_prefix(PREVIEW_ATTRIBUTE) {
+                    link.preview = true;
+                    event = Event::Text(rest.to_string().into());
+                }
+            }
+            // A hidden heading is dropped, so its links claim no number or preview
+            if !hidden_heading {
+                let moved_links = (options.links_in_comment && comment_start.is_none())
+                    .then_some(&mut comment_links);
+                let notes = match comment_start {
+                    Some(_) => &mut comment_notes,
+                    None => &mut post_notes,
+                };
+                finish_link(
+                    &mut output,
+                    &link,
+                    options.link_style,
+                    &mut notes.references,
+                    &mut preview_url,
+                    moved_links,
+                    &mut warnings,
+                );
+            }
+        }
+        if let Some(label) = callout_label.take() {
+            if matches!(
+                event,
+                Event::SoftBreak | Event::HardBreak | Event::End(TagEnd::Paragraph)
+            ) {
+                push_text_with_entities(
+                    &mut output,
+                    &label,
+                    &style_stack,
+                    options,
+                    &mut accidental_hashtags,
+                );
+            }
+        }
+        // A dropped inline comment shouldn't leave a double space behind,
+        // nor reach back before the first comment's split point
+        if std::mem::take(&mut after_inline_comment)
+            && matches!(&event, Event::Text(text) if text.starts_with(' '))
+        {
+            let kept = output
+                .trim_end_matches(' ')
+                .len()
+                .max(comment_start.unwrap_or(0));
+            output.truncate(kept);
+        }
+        after_inline_comment = matches!(
+            &event,
+            Event::InlineHtml(raw) if raw.starts_with("<!--") && raw.ends_with("-->")
+        );
+        let opens_block = matches!(
+            event,
+            Event::Start(
+                Tag::Paragraph
+                    | Tag::Heading { .. }
+                    | Tag::Item
+                    | Tag::TableCell
+                    | Tag::BlockQuote(_)
+                    | Tag::HtmlBlock
+                    | Tag::FootnoteDefinition(_)
+                    | Tag::DefinitionListTitle
+                    | Tag::DefinitionListDefinition
+            ) | Event::TaskListMarker(_)
+        );
         match event {
-            // Headings → Bold
-            Event::Start(Tag::Heading { .. }) => {
-                style_stack.push(TextStyle::Bold);
+            // Headings → per-level style, case, prefix, and underline, adjusted
+            // per heading by `{.class key=value}` attributes
+            Event::Start(Tag::Heading {
+                level,
+                classes,
+                attrs,
+                ..
+            }) => {
+                let level_style = options
+                    .heading_styles
+                    .get(&level)
+                    .unwrap_or(&fallback_heading);
+                let heading_style = if classes.is_empty() && attrs.is_empty() {
+                    Cow::Borrowed(level_style)
+                } else {
+                    match level_style.with_attributes(&classes, &attrs, &options.emoji_map) {
+                        Some(style) => Cow::Owned(style),
+                        None => {
+                            hidden_heading = true;
+                            Cow::Borrowed(level_style)
+                        }
+                    }
+                };
+                heading_preview = classes.iter().any(|class| &**class == PREVIEW_CLASS);
+                let line_start = output.len();
+                if let Some(prefix) = &heading_style.prefix {
+                    output.push_str(prefix);
+                    output.push(' ');
+                }
+                style_stack.push(TextStyle::from(heading_style.family));
+                current_heading = Some((heading_style, line_start));
+                heading_word_start = true;
+                heading_text.clear();
             }
             Event::End(TagEnd::Heading(_)) => {
+                unwind_styles(&mut open_spans, &mut style_stack);
+                unwind_styles(&mut html_tags, &mut style_stack);
                 style_stack.pop();
+                if std::mem::take(&mut heading_preview) {
+                    warnings.push(
+                        "Heading {.preview} ignored: it only marks a link that ends the heading"
+                            .to_string(),
+                    );
+                }
+                if let Some((heading_style, line_start)) = current_heading.take() {
+                    if std::mem::take(&mut hidden_heading) {
+                        output.truncate(line_start);
+                        continue;
+                    }
+                    // `## First comment` starts the comment and isn't shown
+                    if comment_start.is_none()
+                        && footnote_definition.is_none()
+                        && first_comment::is_heading(&heading_text)
+                    {
+                        output.truncate(line_start);
+                        comment_start = Some(line_start);
+                        continue;
+                    }
+                    if let Some(underline) = heading_style.underline {
+                        let line = output.get(line_start..).unwrap_or_default();
+                        let width = display_width(line.trim_end());
+                        output.push('\n');
+                        output.extend(std::iter::repeat_n(underline, width));
+                    }
+                }
                 ensure_blank_line(&mut output);
             }
 
-            // Strong → Bold
-            Event::Start(Tag::Strong) => {
-                let current = *style_stack.last().unwrap_or(&TextStyle::Normal);
-                let new_style = match current {
-                    TextStyle::Italic => TextStyle::BoldItalic,
-                    _ => TextStyle::Bold,
-                };
-                style_stack.push(new_style);
+            // Strong → Bold, Emphasis → Italic, Strikethrough → combining long
+            // stroke, ^sup^ / ~sub~ → Unicode superscripts and subscripts
+            Event::Start(
+                tag @ (Tag::Strong
+                | Tag::Emphasis
+                | Tag::Strikethrough
+                | Tag::Superscript
+                | Tag::Subscript),
+            ) => {
+                let current = current_style(&style_stack);
+                markdown_styles.push(style_stack.len());
+                style_stack.push(match tag {
+                    Tag::Strong => current.bolder(),
+                    Tag::Emphasis => current.italicized(),
+                    Tag::Strikethrough => TextStyle::Strikethrough,
+                    Tag::Superscript => TextStyle::Superscript,
+                    _ => TextStyle::Subscript,
+                });
             }
-            Event::End(TagEnd::Strong) => {
-                style_stack.pop();
+            Event::End(
+                TagEnd::Strong
+                | TagEnd::Emphasis
+                | TagEnd::Strikethrough
+                | TagEnd::Superscript
+                | TagEnd::Subscript,
+            ) => {
+                // Spans and HTML tags left open inside close with it, so
+                // overlapping markup can't leave its style on the stack
+                if let Some(depth) = markdown_styles.pop() {
+                    style_stack.truncate(depth);
+                    open_spans.retain(|(_, open)| *open < depth);
+                    html_tags.retain(|(_, open)| *open < depth);
+                }
             }
 
-            // Emphasis → Italic
-            Event::Start(Tag::Emphasis) => {
-                let current = *style_stack.last().unwrap_or(&TextStyle::Normal);
-                let new_style = match current {
-                    TextStyle::Bold => TextStyle::BoldItalic,
-                    _ => TextStyle::Italic,
-                };
-                style_stack.push(new_style);
+            // Raw HTML → styles, line breaks, and 「kbd」; comments are dropped
+            Event::Start(Tag::HtmlBlock) => {
+                in_html_block = true;
             }
-            Event::End(TagEnd::Emphasis) => {
-                style_stack.pop();
+            Event::End(TagEnd::HtmlBlock) => {
+                in_html_block = false;
+                unwind_styles(&mut html_tags, &mut style_stack);
+                if !output.is_empty() {
+                    ensure_blank_line(&mut output);
+                }
+            }
+            Event::Html(raw) | Event::InlineHtml(raw) => {
+                // Footnote definitions, table cells and headings are cut out
+                // or rewritten later, so a marker there would split nowhere
+                if comment_start.is_none()
+                    && footnote_definition.is_none()
+                    && table.is_none()
+                    && current_heading.is_none()
+                    && first_comment::is_marker(&raw)
+                {
+                    comment_start = Some(output.len());
+                    // Removed link text and the space after the marker stay
+                    // on their own side of the split
+                    block_start = output.len();
+                    after_inline_comment = false;
+                    continue;
+                }
+                for token in html_scanner.scan(&raw) {
+                    match token {
+                        HtmlToken::Text(text) if in_html_block => {
+                            let text = html::decode_entities(text);
+                            let lines: Vec<&str> = text.split('\n').collect();
+                            for (i, line) in lines.iter().enumerate() {
+                                if i > 0 && !output.is_empty() {
+                                    ensure_newline(&mut output);
+                                }
+                                let mut line = *line;
+                                if output.is_empty() || output.ends_with('\n') {
+                                    line = line.trim_start();
+                                }
+                                if i + 1 < lines.len() {
+                                    line = line.trim_end();
+                                }
+                                push_text_with_entities(
+                                    &mut output,
+                                    line,
+                                    &style_stack,
+                                    options,
+                                    &mut accidental_hashtags,
+                                );
+                            }
+                        }
+                        HtmlToken::Text(text) => {
+                            push_text_with_entities(
+                                &mut output,
+                                &html::decode_entities(text),
+                                &style_stack,
+                                options,
+                                &mut accidental_hashtags,
+                            );
+                        }
+                        // `<a href>` → handled like a Markdown link
+                        HtmlToken::Anchor(href) => {
+                            pending_link = Some(PendingLink {
+                                url: urls::rewrite(&href, &options.url_rewrite, &utm_tags)
+                                    .into_owned(),
+                                written_url: href,
+                                text_start: output.len(),
+                                block_start,
+                                autolink: false,
+                                preview: false,
+                            });
+                            html_tags.push(("a".to_string(), style_stack.len()));
+                        }
+                        HtmlToken::Open(name) => {
+                            let current = current_style(&style_stack);
+                            let style = match name.as_str() {
+                                "br" => {
+                                    output.push('\n');
+                                    continue;
+                                }
+                                "p" | "details" => {
+                                    if !output.is_empty() {
+                                        ensure_blank_line(&mut output);
+                                    }
+                                    continue;
+                                }
+                                "b" | "strong" | "summary" => Some(current.bolder()),
+                                "i" | "em" => Some(current.italicized()),
+                                "u" | "ins" => Some(TextStyle::Underline),
+                                "s" | "del" | "strike" => Some(TextStyle::Strikethrough),
+                                "sup" => Some(TextStyle::Superscript),
+                                "sub" => Some(TextStyle::Subscript),
+                                "kbd" => {
+                                    output.push('「');
+                                    None
+                                }
+                                _ => {
+                                    note_unmapped(&mut unmapped_tags, name);
+                                    continue;
+                                }
+                            };
+                            html_tags.push((name, style_stack.len()));
+                            style_stack.extend(style);
+                        }
+                        // `<br/>` breaks the line; `<b/>` and the like have no text to style
+                        HtmlToken::SelfClosing(name) => {
+                            if name == "br" {
+                                output.push('\n');
+                            }
+                        }
+                        HtmlToken::Close(name) => {
+                            match name.as_str() {
+                                "br" => continue,
+                                "p" | "details" => {
+                                    ensure_blank_line(&mut output);
+                                    continue;
+                                }
+                                _ => {}
+                            }
+                            // Unknown tags were already reported when they opened
+                            if let Some(index) =
+                                html_tags.iter().rposition(|(open, _)| *open == name)
+                            {
+                                style_stack.truncate(html_tags[index].1);
+                                html_tags.truncate(index);
+                                match name.as_str() {
+                                    "kbd" => output.push('」'),
+                                    "summary" => ensure_newline(&mut output),
+                                    "a" => {
+                                        if let Some(link) = pending_link.take() {
+                                            let moved_links = (options.links_in_comment
+                                                && comment_start.is_none())
+                                            .then_some(&mut comment_links);
+                                            let notes = match comment_start {
+                                                Some(_) => &mut comment_notes,
+                                                None => &mut post_notes,
+                                            };
+                                            finish_link(
+                                                &mut output,
+                                                &link,
+                                                options.link_style,
+                                                &mut notes.references,
+                                                &mut preview_url,
+                                                moved_links,
+                                                &mut warnings,
+                                            );
+                                        }
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
+                    }
+                }
             }
 
             // Lists → Bullet points
@@ -111,52 +755,204 @@ pub fn convert(markdown: &str, options: &ConvertOptions) -> String {
                 if !output.is_empty() && !output.ends_with('\n') {
                     output.push('\n');
                 }
+                bullet_start = None;
                 if let Some(ctx) = list_stack.last_mut() {
                     if ctx.ordered {
                         let _ = write!(output, "{}. ", ctx.next_index);
                         ctx.next_index += 1;
                     } else {
+                        bullet_start = Some(output.len());
                         output.push_str(&options.bullet);
                         output.push(' ');
                     }
                 } else {
+                    bullet_start = Some(output.len());
                     output.push_str(&options.bullet);
                     output.push(' ');
                 }
             }
+            // Task list markers replace the bullet (ordered items keep their number)
+            Event::TaskListMarker(checked) => {
+                if let Some(start) = bullet_start.take() {
+                    output.truncate(start);
+                }
+                output.push_str(options.task_list_style.glyph(checked));
+                output.push(' ');
+            }
             Event::End(TagEnd::Item) => {
+                unwind_styles(&mut open_spans, &mut style_stack);
+                unwind_styles(&mut html_tags, &mut style_stack);
+                ensure_newline(&mut output);
+            }
+
+            // Definition lists → styled term, then one prefixed line per definition;
+            // inside a list item they sit under the item's text
+            Event::Start(Tag::DefinitionListTitle) => {
+                if !list_stack.is_empty() {
+                    while output.ends_with("\n\n") {
+                        output.pop();
+                    }
+                    ensure_newline(&mut output);
+                } else if !output.is_empty() {
+                    ensure_blank_line(&mut output);
+                }
+                output.push_str(&list_indent(&list_stack, options));
+                style_stack.push(TextStyle::from(options.definition_term_family));
+            }
+            Event::End(TagEnd::DefinitionListTitle) => {
+                style_stack.pop();
+                ensure_newline(&mut output);
+            }
+            Event::Start(Tag::DefinitionListDefinition) => {
+                ensure_newline(&mut output);
+                output.push_str(&list_indent(&list_stack, options));
+                output.push_str(options.definition_layout.prefix());
+                definition_paragraphs = Some(0);
+            }
+            Event::End(TagEnd::DefinitionListDefinition) => {
+                definition_paragraphs = None;
                 ensure_newline(&mut output);
             }
+            Event::End(TagEnd::DefinitionList) if list_stack.is_empty() => {
+                ensure_blank_line(&mut output);
+            }
 
-            // Blockquotes → Italic
-            Event::Start(Tag::BlockQuote) => {
+            // Blockquotes → Italic, unless they open with a `[!KIND]` callout marker
+            Event::Start(Tag::BlockQuote(_)) => {
+                pending_callout = Some(style_stack.len());
                 style_stack.push(TextStyle::Italic);
             }
-            Event::End(TagEnd::BlockQuote) => {
+            Event::End(TagEnd::BlockQuote(_)) => {
+                pending_callout = None;
                 style_stack.pop();
                 ensure_newline(&mut output);
             }
 
-            // Links → text (url)
-            Event::Start(Tag::Link { dest_url, .. }) => {
-                pending_link_url = Some(dest_url.to_string());
+            // YAML front matter → fields for UTM placeholders, not shown
+            Event::Start(Tag::MetadataBlock(_)) => {
+                in_front_matter = true;
+            }
+            Event::End(TagEnd::MetadataBlock(_)) => {
+                in_front_matter = false;
+                utm_tags = options.url_rewrite.tags(&front_matter);
+            }
+
+            // [[Wikilinks]] → resolved URL, or just the alias text with a warning
+            Event::Start(Tag::Link {
+                link_type: LinkType::WikiLink { .. },
+                dest_url,
+                ..
+            })
+            | Event::Start(Tag::Image {
+                link_type: LinkType::WikiLink { .. },
+                dest_url,
+                ..
+            }) => {
+                pending_link = wikilink::resolve(
+                    &dest_url,
+                    options.wikilink_template.as_deref(),
+                    &options.wikilink_urls,
+                )
+                .map(|url| PendingLink {
+                    url: urls::rewrite(&url, &options.url_rewrite, &utm_tags).into_owned(),
+                    written_url: url,
+                    text_start: output.len(),
+                    block_start,
+                    autolink: false,
+                    preview: false,
+                });
+                if pending_link.is_none()
+                    && !unresolved_wikilinks.iter().any(|name| *name == *dest_url)
+                {
+                    unresolved_wikilinks.push(dest_url.to_string());
+                }
+            }
+
+            // Links → text (url), or as set by `link_style`; images → alt (url)
+            Event::Start(Tag::Link {
+                link_type,
+                dest_url,
+                title,
+                ..
+            })
+            | Event::Start(Tag::Image {
+                link_type,
+                dest_url,
+                title,
+                ..
+            }) => {
+                pending_link = Some(PendingLink {
+                    url: urls::rewrite(&dest_url, &options.url_rewrite, &utm_tags).into_owned(),
+                    written_url: dest_url.to_string(),
+                    text_start: output.len(),
+                    block_start,
+                    autolink: matches!(link_type, LinkType::Autolink | LinkType::Email),
+                    preview: title.eq_ignore_ascii_case("preview"),
+                });
             }
-            Event::End(TagEnd::Link) => {
-                if let Some(url) = pending_link_url.take() {
-                    output.push_str(" (");
-                    output.push_str(&url);
-                    output.push(')');
+            // Finished on the next event, which may start with `{.preview}`
+            Event::End(TagEnd::Link) | Event::End(TagEnd::Image) => {
+                ended_link = pending_link.take();
+            }
+
+            // Tables → grid, key/value lines, or bullets
+            Event::Start(Tag::Table(alignments)) => {
+                table = Some(Table::new(alignments));
+            }
+            Event::End(TagEnd::Table) => {
+                if let Some(table) = table.take() {
+                    output.push_str(&table.render(
+                        options.table_mode,
+                        options.table_padding,
+                        &options.bullet,
+                    ));
+                    ensure_blank_line(&mut output);
+                }
+            }
+            Event::Start(Tag::TableHead) | Event::Start(Tag::TableRow) => {
+                table_row.clear();
+            }
+            Event::End(TagEnd::TableHead) => {
+                if let Some(table) = table.as_mut() {
+                    table.head = std::mem::take(&mut table_row);
+                }
+            }
+            Event::End(TagEnd::TableRow) => {
+                if let Some(table) = table.as_mut() {
+                    table.rows.push(std::mem::take(&mut table_row));
                 }
             }
-            // Images → alt (url)
-            Event::Start(Tag::Image { dest_url, .. }) => {
-                pending_link_url = Some(dest_url.to_string());
+            Event::Start(Tag::TableCell) => {
+                table_cell_start = output.len();
             }
-            Event::End(TagEnd::Image) => {
-                if let Some(url) = pending_link_url.take() {
-                    output.push_str(" (");
-                    output.push_str(&url);
-                    output.push(')');
+            Event::End(TagEnd::TableCell) => {
+                let cell = output.split_off(table_cell_start);
+                table_row.push(cell.trim().to_string());
+            }
+
+            // Footnotes → superscript references, definitions collected into notes
+            Event::FootnoteReference(label) if !hidden_heading => {
+                let notes = match comment_start {
+                    Some(_) => &mut comment_notes,
+                    None => &mut post_notes,
+                };
+                let number = notes.footnotes.reference(&label);
+                output.push_str(&footnotes::marker(number, options.plain));
+            }
+            Event::Start(Tag::FootnoteDefinition(label)) => {
+                footnote_definition = Some((label.to_string(), output.len()));
+            }
+            Event::End(TagEnd::FootnoteDefinition) => {
+                if let Some((label, start)) = footnote_definition.take() {
+                    let text = output.split_off(start);
+                    let text: Vec<&str> = text
+                        .lines()
+                        .map(str::trim)
+                        .filter(|line| !line.is_empty())
+                        .collect();
+                    let text = text.join(" ");
+                    comment_notes.footnotes.define(&label, text.clone());
+                    post_notes.footnotes.define(&label, text);
                 }
             }
 
@@ -216,28 +1012,159 @@ pub fn convert(markdown: &str, options: &ConvertOptions) -> String {
                 // Otherwise: skip code block entirely
             }
 
-            // Inline code → Just text
-            Event::Code(code) => {
-                output.push_str(&code);
+            // Inline code → Monospace, or wrapped in backticks/guillemets
+            Event::Code(code) => match options.inline_code {
+                InlineCodeStyle::Monospace if options.plain => output.push_str(&code),
+                InlineCodeStyle::Monospace => output.push_str(&to_monospace(&code)),
+                InlineCodeStyle::Backticks => {
+                    let _ = write!(output, "`{}`", code);
+                }
+                InlineCodeStyle::Guillemets => {
+                    let _ = write!(output, "‹{}›", code);
+                }
+            },
+
+            // $math$ → Unicode symbols; $$display$$ math gets its own lines
+            Event::InlineMath(tex) => {
+                push_math(&mut output, &tex, options, &mut unsupported_math);
+            }
+            Event::DisplayMath(tex) => {
+                if !output.is_empty() {
+                    ensure_newline(&mut output);
+                }
+                push_math(&mut output, &tex, options, &mut unsupported_math);
+                ensure_newline(&mut output);
             }
 
             // Text → Apply current style
             Event::Text(text) => {
                 if in_code_block {
                     code_block_content.push_str(&text);
+                    continue;
+                }
+                if in_front_matter {
+                    front_matter.extend(front_matter::parse(&text));
+                    continue;
+                }
+                if let Some(quote_depth) = pending_callout.take() {
+                    if let Some(marker) = callout::parse_marker(&text) {
+                        // Callouts are labeled blocks, not quotes
+                        style_stack[quote_depth] = TextStyle::Normal;
+                        let icon = options
+                            .callout_icons
+                            .get(&callout::canonical_kind(marker.kind))
+                            .or_else(|| options.callout_icons.get("note"));
+                        if let Some(icon) = icon {
+                            output.push_str(icon);
+                            output.push(' ');
+                        }
+                        block_start = output.len();
+                        callout_title = Some(style_stack.len());
+                        style_stack.push(current_style(&style_stack).bolder());
+                        // The title may also start with markup, as in `[!NOTE] **Bold**`
+                        match marker.title {
+                            "" => callout_label = Some(marker.label()),
+                            title => push_text_with_entities(
+                                &mut output,
+                                title,
+                                &style_stack,
+                                options,
+                                &mut accidental_hashtags,
+                            ),
+                        }
+                        continue;
+                    }
+                }
+                let text = punctuation::localize_quotes(
+                    &text,
+                    options.quote_style,
+                    &mut single_quote_open,
+                );
+                let text = if options.emoji_shortcodes {
+                    emoji::expand_shortcodes(&text, &options.emoji_map)
                 } else {
-                    let style = *style_stack.last().unwrap_or(&TextStyle::Normal);
-                    if options.plain || style == TextStyle::Normal {
-                        output.push_str(&text);
-                    } else {
-                        let styled = apply_style(&text, style, false);
-                        output.push_str(&styled);
+                    Cow::Borrowed(&*text)
+                };
+                if current_heading.is_some() {
+                    heading_text.push_str(&text);
+                }
+                let tokens = if options.inline_spans {
+                    spans::tokenize(&text)
+                } else {
+                    vec![SpanToken::Text(&text)]
+                };
+                for token in tokens {
+                    match token {
+                        SpanToken::Text(chunk) => {
+                            for segment in entities::split(chunk) {
+                                let Some(chunk) =
+                                    push_entity(&mut output, segment, &mut accidental_hashtags)
+                                else {
+                                    heading_word_start = false;
+                                    continue;
+                                };
+                                let chunk: Cow<str> = match &current_heading {
+                                    Some((heading_style, _)) => {
+                                        let cased =
+                                            heading_style.apply_case(chunk, heading_word_start);
+                                        heading_word_start = chunk.ends_with(char::is_whitespace);
+                                        Cow::Owned(cased)
+                                    }
+                                    None => Cow::Borrowed(chunk),
+                                };
+                                for run in spans::script_runs(&chunk, options.subscript) {
+                                    let (text, style) = match run {
+                                        ScriptRun::Text(text) => (text, None),
+                                        ScriptRun::Superscript(text) => {
+                                            (text, Some(TextStyle::Superscript))
+                                        }
+                                        ScriptRun::Subscript(text) => {
+                                            (text, Some(TextStyle::Subscript))
+                                        }
+                                    };
+                                    style_stack.extend(style);
+                                    push_text(&mut output, text, &style_stack, options);
+                                    if style.is_some() {
+                                        style_stack.pop();
+                                    }
+                                }
+                            }
+                        }
+                        SpanToken::Open(family) => {
+                            open_spans.push((OpenSpan::Bracket, style_stack.len()));
+                            style_stack.push(TextStyle::from(family));
+                        }
+                        SpanToken::Highlight
+                            if open_spans.last().map(|(kind, _)| *kind)
+                                != Some(OpenSpan::Highlight) =>
+                        {
+                            open_spans.push((OpenSpan::Highlight, style_stack.len()));
+                            style_stack.push(TextStyle::from(options.highlight_family));
+                        }
+                        SpanToken::Close | SpanToken::Highlight => {
+                            let kind = match token {
+                                SpanToken::Close => OpenSpan::Bracket,
+                                _ => OpenSpan::Highlight,
+                            };
+                            match open_spans.last() {
+                                Some((open, depth))
+                                    if *open == kind && style_stack.len() == depth + 1 =>
+                                {
+                                    style_stack.pop();
+                                    open_spans.pop();
+                                }
+                                _ => push_text(&mut output, token.literal(), &style_stack, options),
+                            }
+                        }
                     }
                 }
             }
 
             // Soft/Hard breaks
             Event::SoftBreak => {
+                if let Some(depth) = callout_title.take() {
+                    style_stack.truncate(depth);
+                }
                 if in_code_block {
                     code_block_content.push('\n');
                 } else {
@@ -245,6 +1172,9 @@ pub fn convert(markdown: &str, options: &ConvertOptions) -> String {
                 }
             }
             Event::HardBreak => {
+                if let Some(depth) = callout_title.take() {
+                    style_stack.truncate(depth);
+                }
                 if in_code_block {
                     code_block_content.push('\n');
                 } else {
@@ -252,103 +1182,789 @@ pub fn convert(markdown: &str, options: &ConvertOptions) -> String {
                 }
             }
 
+            // Thematic breaks → separator line surrounded by blank lines
+            Event::Rule => {
+                if !output.is_empty() {
+                    ensure_blank_line(&mut output);
+                }
+                output.push_str(options.rule.separator());
+                output.push('\n');
+                ensure_blank_line(&mut output);
+            }
+
             // Paragraphs
+            Event::Start(Tag::Paragraph) => {
+                // Later paragraphs of a definition line up under the first
+                if let Some(count) = definition_paragraphs.as_mut() {
+                    if *count > 0 {
+                        output.push_str(&list_indent(&list_stack, options));
+                        let width = options.definition_layout.prefix().chars().count();
+                        output.extend(std::iter::repeat_n(' ', width));
+                    }
+                    *count += 1;
+                }
+            }
             Event::End(TagEnd::Paragraph) => {
-                ensure_blank_line(&mut output);
+                pending_callout = None;
+                single_quote_open = false;
+                if let Some(depth) = callout_title.take() {
+                    style_stack.truncate(depth);
+                }
+                unwind_styles(&mut open_spans, &mut style_stack);
+                unwind_styles(&mut html_tags, &mut style_stack);
+                if definition_paragraphs.is_some() {
+                    ensure_newline(&mut output);
+                } else {
+                    ensure_blank_line(&mut output);
+                }
             }
 
             _ => {}
         }
+        if opens_block {
+            block_start = output.len();
+        }
     }
 
-    if options.trim_output {
-        output.trim().to_string()
-    } else {
-        output
+    // Each part lists the footnotes and references its own text points to
+    let mut first_comment = comment_start.map(|start| {
+        let comment = output.split_off(start);
+        let kept = output.trim_end_matches(' ').len();
+        output.truncate(kept);
+        comment
+    });
+    if let Some(comment) = &mut first_comment {
+        comment_notes.push_to(comment, options);
     }
-}
+    if !comment_links.is_empty() {
+        let comment = first_comment.get_or_insert_with(String::new);
+        ensure_blank_line(comment);
+        let lines: Vec<&str> = comment_links
+            .iter()
+            .map(|(_, line)| line.as_str())
+            .collect();
+        comment.push_str(&lines.join("\n"));
+    }
+    post_notes.push_to(&mut output, options);
 
-fn apply_style(text: &str, style: TextStyle, plain: bool) -> String {
-    if plain {
-        return text.to_string();
+    // LinkedIn builds the preview card from the URL on the last line
+    if let Some(url) = &preview_url {
+        ensure_blank_line(&mut output);
+        output.push_str(url);
     }
 
-    match style {
-        TextStyle::Normal => text.to_string(),
-        TextStyle::Bold => to_bold(text),
-        TextStyle::Italic => to_italic(text),
-        TextStyle::BoldItalic => to_bold_italic(text),
+    if !unmapped_tags.is_empty() {
+        warnings.push(format!(
+            "HTML tags not converted: {}",
+            unmapped_tags
+                .iter()
+                .map(|name| format!("<{}>", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
     }
-}
 
-fn ensure_newline(output: &mut String) {
-    if !output.ends_with('\n') {
-        output.push('\n');
+    if !unsupported_math.is_empty() {
+        warnings.push(format!(
+            "LaTeX commands not converted: {}",
+            unsupported_math.join(", ")
+        ));
     }
-}
 
-fn ensure_blank_line(output: &mut String) {
-    if output.ends_with("\n\n") {
-        return;
+    // One warning per unresolved link, however often it is used
+    warnings.extend(
+        unresolved_wikilinks
+            .iter()
+            .map(|name| format!("Unresolved wikilink: [[{}]]", name)),
+    );
+
+    if !accidental_hashtags.is_empty() {
+        warnings.push(format!(
+            "LinkedIn will turn these into hashtag links: {}",
+            accidental_hashtags.join(", ")
+        ));
     }
 
-    if output.ends_with('\n') {
-        output.push('\n');
+    let text = if options.trim_output {
+        output.trim().to_string()
     } else {
-        output.push_str("\n\n");
+        output
+    };
+    let first_comment = first_comment
+        .map(|comment| comment.trim().to_string())
+        .filter(|comment| !comment.is_empty());
+    Conversion {
+        text,
+        warnings,
+        preview_url,
+        first_comment,
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::unicode::{to_bold, to_bold_italic};
-
-    fn opts() -> ConvertOptions {
-        ConvertOptions::default()
+/// Turn `^-^`-style sup/sub spans with no letters or digits back into text,
+/// so emoticons aren't rendered as a raised or lowered sign.
+fn literal_emoticons<'a>(events: impl Iterator<Item = Event<'a>>) -> Vec<Event<'a>> {
+    let mut out: Vec<Event<'a>> = Vec::new();
+    for event in events {
+        let marker = match &event {
+            Event::End(TagEnd::Superscript) => '^',
+            Event::End(TagEnd::Subscript) => '~',
+            _ => {
+                out.push(event);
+                continue;
+            }
+        };
+        let literal = match out.as_slice() {
+            [.., Event::Start(Tag::Superscript | Tag::Subscript), Event::Text(text)]
+                if !spans::is_script_content(text) =>
+            {
+                Some(format!("{}{}{}", marker, text, marker))
+            }
+            _ => None,
+        };
+        match literal {
+            Some(literal) => {
+                out.truncate(out.len() - 2);
+                out.push(Event::Text(literal.into()));
+            }
+            None => out.push(event),
+        }
     }
+    out
+}
 
-    fn read_fixture(path: &str) -> String {
-        std::fs::read_to_string(path).unwrap().replace("\r\n", "\n")
+fn push_text(output: &mut String, text: &str, style_stack: &[TextStyle], options: &ConvertOptions) {
+    if style_stack.iter().all(|s| *s == TextStyle::Normal) {
+        output.push_str(text);
+    } else if options.plain {
+        // Plain text still needs to show what was raised or lowered
+        if style_stack.contains(&TextStyle::Superscript) {
+            let _ = write!(output, "^({})", text);
+        } else if style_stack.contains(&TextStyle::Subscript) {
+            let _ = write!(output, "_({})", text);
+        } else {
+            output.push_str(text);
+        }
+    } else {
+        output.push_str(&style_text(text, style_stack, options));
     }
+}
 
-    #[test]
-    fn test_bold() {
-        let result = convert("**hello**", &opts());
-        assert_eq!(result, "𝐡𝐞𝐥𝐥𝐨");
-    }
+/// Write a URL, hashtag or mention as plain characters, the only way LinkedIn
+/// links them; the text around them is handed back to be styled.
+fn push_entity<'a>(
+    output: &mut String,
+    segment: Segment<'a>,
+    accidental_hashtags: &mut Vec<String>,
+) -> Option<&'a str> {
+    let raw = match segment {
+        Segment::Text(text) => return Some(text),
+        Segment::Url(raw) | Segment::Mention(raw) => raw,
+        Segment::Hashtag(raw) => {
+            if entities::is_accidental_hashtag(raw)
+                && !accidental_hashtags.iter().any(|tag| tag == raw)
+            {
+                accidental_hashtags.push(raw.to_string());
+            }
+            raw
+        }
+    };
+    output.push_str(raw);
+    None
+}
 
-    #[test]
-    fn test_italic() {
-        let result = convert("*hello*", &opts());
-        // Mathematical Italic: h=U+1D455, e=U+1D452, l=U+1D459, o=U+1D45C
-        assert_eq!(result, "\u{1D455}\u{1D452}\u{1D459}\u{1D459}\u{1D45C}");
+/// `push_text` for text that may contain URLs, hashtags or mentions.
+fn push_text_with_entities(
+    output: &mut String,
+    text: &str,
+    style_stack: &[TextStyle],
+    options: &ConvertOptions,
+    accidental_hashtags: &mut Vec<String>,
+) {
+    for segment in entities::split(text) {
+        if let Some(text) = push_entity(output, segment, accidental_hashtags) {
+            push_text(output, text, style_stack, options);
+        }
     }
+}
 
-    #[test]
-    fn test_header() {
-        let result = convert("# Header", &opts());
-        assert_eq!(result, "𝐇𝐞𝐚𝐝𝐞𝐫");
+/// LaTeX source as-is in plain mode, otherwise its Unicode rendering.
+fn push_math(
+    output: &mut String,
+    tex: &str,
+    options: &ConvertOptions,
+    unsupported_math: &mut Vec<String>,
+) {
+    if options.plain {
+        output.push_str(tex.trim());
+        return;
     }
-
-    #[test]
-    fn test_list() {
-        let result = convert("- item one\n- item two", &opts());
-        assert!(result.contains("• item one"));
-        assert!(result.contains("• item two"));
+    let converted = math::to_unicode(tex);
+    output.push_str(&converted.text);
+    for command in converted.unsupported {
+        if !unsupported_math.contains(&command) {
+            unsupported_math.push(command);
+        }
     }
+}
 
-    #[test]
-    fn test_ordered_list() {
-        let result = convert("1. first\n2. second", &opts());
-        assert!(result.contains("1. first"));
-        assert!(result.contains("2. second"));
+/// Follow a link's text with its URL as set by `style`. Autolinks and links
+/// whose text is the URL show it once, so it never appears twice. The first
+/// preview link keeps only its text and claims `preview_url`; with
+/// `moved_links`, external links do the same and their URLs go to the comment.
+fn finish_link(
+    output: &mut String,
+    link: &PendingLink,
+    style: LinkStyle,
+    references: &mut LinkReferences,
+    preview_url: &mut Option<String>,
+    moved_links: Option<&mut Vec<(String, String)>>,
+    warnings: &mut Vec<String>,
+) {
+    if let Some(chosen) = preview_url.as_ref().filter(|_| link.preview) {
+        warnings.push(format!(
+            "Preview link ignored, already using {}: {}",
+            chosen, link.url
+        ));
     }
-
-    #[test]
-    fn test_link() {
-        let result = convert("[click here](https://example.com)", &opts());
-        assert_eq!(result, "click here (https://example.com)");
+    let shown = links::display_url(&link.url);
+    let Some(text) = output.get(link.text_start..).map(str::trim) else {
+        return;
+    };
+    let written_shown = links::display_url(&link.written_url);
+    let text_is_url = link.autolink
+        || [link.url.as_str(), shown, &link.written_url, written_shown].contains(&text);
+    if link.preview && preview_url.is_none() {
+        // The URL goes on its own line at the end; only the text stays here
+        *preview_url = Some(shown.to_string());
+        if text_is_url {
+            remove_link_text(output, link);
+        }
+        return;
+    }
+    if let Some(moved_links) = moved_links.filter(|_| links::is_external(&link.url)) {
+        // Only the text stays in the post; the comment gets `text: url`
+        let line = if text_is_url || text.is_empty() {
+            shown.to_string()
+        } else {
+            format!("{}: {}", text, shown)
+        };
+        if text_is_url {
+            remove_link_text(output, link);
+        }
+        if !moved_links.iter().any(|(url, _)| url == shown) {
+            moved_links.push((shown.to_string(), line));
+        }
+        return;
+    }
+    if text.is_empty() {
+        output.push_str(shown);
+        return;
+    }
+    if text_is_url {
+        // The text is the URL itself; show it once, as rewritten
+        if link.url != link.written_url {
+            output.truncate(link.text_start);
+            output.push_str(shown);
+        }
+        return;
+    }
+    match style {
+        LinkStyle::Inline => {
+            let _ = write!(output, " ({})", shown);
+        }
+        LinkStyle::References => {
+            let _ = write!(output, " [{}]", references.number(shown));
+        }
+        LinkStyle::Domain => {
+            let _ = write!(output, " ({})", links::domain(&link.url));
+        }
+        LinkStyle::Text => {}
+        LinkStyle::Url => {
+            output.truncate(link.text_start);
+            output.push_str(shown);
+        }
+    }
+}
+
+/// Ends of blocks and table cells, which Markdown links never span.
+fn is_block_end(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::End(
+            TagEnd::Paragraph
+                | TagEnd::Heading(_)
+                | TagEnd::TableCell
+                | TagEnd::Item
+                | TagEnd::BlockQuote(_)
+                | TagEnd::HtmlBlock
+                | TagEnd::FootnoteDefinition
+                | TagEnd::DefinitionListTitle
+                | TagEnd::DefinitionListDefinition
+        )
+    )
+}
+
+/// Remove a link's text, and the spaces before it, when its URL is shown elsewhere.
+fn remove_link_text(output: &mut String, link: &PendingLink) {
+    let Some(before) = output.get(..link.text_start) else {
+        return;
+    };
+    let kept = before
+        .trim_end_matches(' ')
+        .len()
+        .max(link.block_start.min(before.len()));
+    output.truncate(kept);
+}
+
+/// Drop spans or HTML tags left open at the end of a block so they don't leak
+/// into the next one.
+fn unwind_styles<T>(open: &mut Vec<(T, usize)>, style_stack: &mut Vec<TextStyle>) {
+    if let Some((_, depth)) = open.first() {
+        style_stack.truncate(*depth);
+        open.clear();
+    }
+}
+
+fn note_unmapped(unmapped_tags: &mut Vec<String>, name: String) {
+    if !unmapped_tags.contains(&name) {
+        unmapped_tags.push(name);
+    }
+}
+
+/// The innermost letter style on the stack, ignoring decorations.
+fn current_style(style_stack: &[TextStyle]) -> TextStyle {
+    style_stack
+        .iter()
+        .rev()
+        .copied()
+        .find(|style| !style.is_decoration())
+        .unwrap_or(TextStyle::Normal)
+}
+
+/// Apply the current letter style, then every decoration on the stack.
+fn style_text(text: &str, style_stack: &[TextStyle], options: &ConvertOptions) -> String {
+    let mut styled = if style_stack.contains(&TextStyle::Superscript) {
+        to_superscript(text)
+    } else if style_stack.contains(&TextStyle::Subscript) {
+        to_subscript(text)
+    } else {
+        apply_style(text, current_style(style_stack), options)
+    };
+    if style_stack.contains(&TextStyle::Strikethrough) {
+        styled = to_strikethrough(&styled);
+    }
+    if style_stack.contains(&TextStyle::Underline) {
+        styled = to_underline(&styled);
+    }
+    styled
+}
+
+fn apply_style(text: &str, style: TextStyle, options: &ConvertOptions) -> String {
+    let family = match style {
+        TextStyle::Normal => return text.to_string(),
+        TextStyle::Bold => StyleFamily::Bold,
+        TextStyle::Italic => StyleFamily::Italic,
+        TextStyle::BoldItalic => StyleFamily::BoldItalic,
+        TextStyle::Strikethrough => return to_strikethrough(text),
+        TextStyle::Underline => return to_underline(text),
+        TextStyle::Superscript => return to_superscript(text),
+        TextStyle::Subscript => return to_subscript(text),
+        TextStyle::Family(family) => family,
+    };
+    family.apply_with(text, options.style_digits, options.unstyled_fallback)
+}
+
+/// Spaces that line nested blocks up with the text of the enclosing list items.
+fn list_indent(list_stack: &[ListContext], options: &ConvertOptions) -> String {
+    let width = options.bullet.chars().count() + 1;
+    " ".repeat(width * list_stack.len())
+}
+
+fn ensure_newline(output: &mut String) {
+    if !output.ends_with('\n') {
+        output.push('\n');
+    }
+}
+
+fn ensure_blank_line(output: &mut String) {
+    if output.ends_with("\n\n") {
+        return;
+    }
+
+    if output.ends_with('\n') {
+        output.push('\n');
+    } else {
+        output.push_str("\n\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unicode::{to_bold, to_bold_italic, to_strikethrough, to_underline};
+
+    fn opts() -> ConvertOptions {
+        ConvertOptions::default()
+    }
+
+    fn read_fixture(path: &str) -> String {
+        std::fs::read_to_string(path).unwrap().replace("\r\n", "\n")
+    }
+
+    #[test]
+    fn test_bold() {
+        let result = convert("**hello**", &opts());
+        assert_eq!(result, "𝐡𝐞𝐥𝐥𝐨");
+    }
+
+    #[test]
+    fn test_italic() {
+        let result = convert("*hello*", &opts());
+        // Mathematical Italic: h=U+210E (Planck constant), e=U+1D452, l=U+1D459, o=U+1D45C
+        assert_eq!(result, "\u{210E}\u{1D452}\u{1D459}\u{1D459}\u{1D45C}");
+    }
+
+    #[test]
+    fn test_header() {
+        let result = convert("# Header", &opts());
+        assert_eq!(result, "𝐇𝐞𝐚𝐝𝐞𝐫\n━━━━━━");
+    }
+
+    #[test]
+    fn test_heading_digits() {
+        let result = convert("## Top 10 tips", &opts());
+        assert_eq!(result, "𝐓𝐨𝐩 𝟏𝟎 𝐭𝐢𝐩𝐬");
+    }
+
+    #[test]
+    fn test_heading_digits_unstyled() {
+        let mut options = opts();
+        options.style_digits = false;
+        let result = convert("## Top 10 tips", &options);
+        assert_eq!(result, "𝐓𝐨𝐩 10 𝐭𝐢𝐩𝐬");
+    }
+
+    #[test]
+    fn test_bold_accented() {
+        let result = convert("**Canción**", &opts());
+        assert_eq!(result, "𝐂𝐚𝐧𝐜𝐢𝐨\u{0301}𝐧");
+    }
+
+    #[test]
+    fn test_unstyled_fallback_brackets() {
+        let mut options = opts();
+        options.unstyled_fallback = UnstyledFallback::Brackets;
+        let result = convert("**Go Москва**", &options);
+        assert_eq!(result, "𝐆𝐨 [Москва]");
+    }
+
+    #[test]
+    fn test_heading_levels_differ() {
+        let result = convert("## Two\n\n### Three", &opts());
+        assert_eq!(
+            result,
+            format!("{}\n\n{}", to_bold("Two"), to_bold_italic("Three"))
+        );
+    }
+
+    #[test]
+    fn test_heading_style_prefix_and_case() {
+        let mut options = opts();
+        options.heading_styles.insert(
+            HeadingLevel::H2,
+            HeadingStyle::parse("normal,title,prefix=🚀").unwrap(),
+        );
+        let result = convert("## top ten *tips*", &options);
+        assert_eq!(
+            result,
+            format!("🚀 Top Ten {}", crate::unicode::to_italic("Tips"))
+        );
+    }
+
+    #[test]
+    fn test_heading_family_nesting() {
+        let mut options = opts();
+        options
+            .heading_styles
+            .insert(HeadingLevel::H2, HeadingStyle::parse("script").unwrap());
+        let result = convert("## Big **Easy**", &options);
+        assert_eq!(result, "ℬ𝒾ℊ 𝓔𝓪𝓼𝔂");
+    }
+
+    #[test]
+    fn test_heading_upper_with_underline() {
+        let mut options = opts();
+        options.plain = true;
+        options.heading_styles.insert(
+            HeadingLevel::H1,
+            HeadingStyle::parse("bold,upper,underline=═").unwrap(),
+        );
+        let result = convert("# Launch day", &options);
+        assert_eq!(result, "LAUNCH DAY\n══════════");
+    }
+
+    #[test]
+    fn test_heading_underline_skips_combining_marks() {
+        let result = convert("# Café", &opts());
+        assert_eq!(result, format!("{}\n━━━━", to_bold("Café")));
+    }
+
+    #[test]
+    fn test_list() {
+        let result = convert("- item one\n- item two", &opts());
+        assert!(result.contains("• item one"));
+        assert!(result.contains("• item two"));
+    }
+
+    #[test]
+    fn test_ordered_list() {
+        let result = convert("1. first\n2. second", &opts());
+        assert!(result.contains("1. first"));
+        assert!(result.contains("2. second"));
+    }
+
+    #[test]
+    fn test_link() {
+        let result = convert("[click here](https://example.com)", &opts());
+        assert_eq!(result, "click here (https://example.com)");
+    }
+
+    #[test]
+    fn test_autolink_not_duplicated() {
+        let result = convert("<https://x.io> and <me@x.io>", &opts());
+        assert_eq!(result, "https://x.io and me@x.io");
+        let result = convert("[https://x.io](https://x.io)", &opts());
+        assert_eq!(result, "https://x.io");
+    }
+
+    #[test]
+    fn test_mailto_link_hides_scheme() {
+        let result = convert("[Email me](mailto:me@x.io)", &opts());
+        assert_eq!(result, "Email me (me@x.io)");
+    }
+
+    #[test]
+    fn test_link_references_share_numbers() {
+        let mut options = opts();
+        options.link_style = LinkStyle::References;
+        let result = convert(
+            "[a](https://a.io), [b](https://b.io) and [a again](https://a.io)",
+            &options,
+        );
+        assert_eq!(
+            result,
+            "a [1], b [2] and a again [1]\n\n[1] https://a.io\n[2] https://b.io"
+        );
+    }
+
+    #[test]
+    fn test_link_domain_text_and_url_styles() {
+        let markdown = "[docs](https://www.example.com/guide?x=1)";
+        let mut options = opts();
+        options.link_style = LinkStyle::Domain;
+        assert_eq!(convert(markdown, &options), "docs (example.com)");
+        options.link_style = LinkStyle::Text;
+        assert_eq!(convert(markdown, &options), "docs");
+        options.link_style = LinkStyle::Url;
+        assert_eq!(
+            convert(markdown, &options),
+            "https://www.example.com/guide?x=1"
+        );
+    }
+
+    #[test]
+    fn test_link_urls_cleaned_and_tagged_from_front_matter() {
+        let mut options = opts();
+        options.url_rewrite.utm = vec![("utm_campaign".to_string(), "{slug}".to_string())];
+        let result = convert(
+            "---\ntitle: Launch Day\n---\n\n[post](https://x.io/?fbclid=1) ![chart](https://x.io/c.png) <https://x.io/a?gclid=2>",
+            &options,
+        );
+        assert_eq!(
+            result,
+            "post (https://x.io/?utm_campaign=launch-day) chart (https://x.io/c.png?utm_campaign=launch-day) https://x.io/a?utm_campaign=launch-day"
+        );
+    }
+
+    #[test]
+    fn test_link_urls_kept_without_clean() {
+        let mut options = opts();
+        options.url_rewrite.clean = false;
+        let result = convert("[post](https://X.io/?fbclid=1)", &options);
+        assert_eq!(result, "post (https://X.io/?fbclid=1)");
+    }
+
+    #[test]
+    fn test_preview_link_moves_to_end() {
+        let conversion = convert_document(
+            "Read [the launch](https://x.io/launch \"preview\") and [docs](https://x.io/docs).\n\nThanks!",
+            &opts(),
+        );
+        assert_eq!(
+            conversion.text,
+            "Read the launch and docs (https://x.io/docs).\n\nThanks!\n\nhttps://x.io/launch"
+        );
+        assert_eq!(
+            conversion.preview_url.as_deref(),
+            Some("https://x.io/launch")
+        );
+    }
+
+    #[test]
+    fn test_preview_attribute_and_second_preview_warns() {
+        let conversion = convert_document(
+            "Try <https://x.io>{.preview} or [y](https://y.io){.preview}.",
+            &opts(),
+        );
+        assert_eq!(conversion.text, "Try or y (https://y.io).\n\nhttps://x.io");
+        assert_eq!(
+            conversion.warnings,
+            vec!["Preview link ignored, already using https://x.io: https://y.io".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_preview_link_alone_in_list_heading() {
+        let result = convert("- # [https://x.io](https://x.io \"preview\")", &opts());
+        assert_eq!(result, "• \n\nhttps://x.io");
+
+        let mut options = opts();
+        options.links_in_comment = true;
+        let conversion = convert_document("- # <https://x.io>", &options);
+        assert_eq!(conversion.text, "•");
+        assert_eq!(conversion.first_comment.as_deref(), Some("https://x.io"));
+    }
+
+    #[test]
+    fn test_preview_attribute_ending_a_heading() {
+        let conversion = convert_document(
+            "## Launch [notes](https://x.io){.preview}\n\n## Plain {.preview}\n\nBody",
+            &opts(),
+        );
+        assert_eq!(
+            conversion.text,
+            format!(
+                "{}\n\n{}\n\nBody\n\nhttps://x.io",
+                to_bold("Launch notes"),
+                to_bold("Plain")
+            )
+        );
+        assert_eq!(
+            conversion.warnings,
+            vec![
+                "Heading {.preview} ignored: it only marks a link that ends the heading"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_first_comment_heading_splits_document() {
+        let conversion = convert_document(
+            "Post body.[^1]\n\n## First Comment\n\nLinks go here.\n\n[^1]: A note.",
+            &opts(),
+        );
+        assert_eq!(
+            conversion.text,
+            format!("Post body.¹\n\n{}\n¹ A note.", to_bold("Notes"))
+        );
+        assert_eq!(conversion.first_comment.as_deref(), Some("Links go here."));
+    }
+
+    #[test]
+    fn test_first_comment_lists_its_own_notes_and_references() {
+        let mut options = opts();
+        options.link_style = LinkStyle::References;
+        options.footnote_heading = String::new();
+        let conversion = convert_document(
+            "See [docs](https://a.test).[^a]\n\n<!-- first-comment -->\n\nAlso [docs](https://a.test) and [blog](https://b.test).[^b]\n\n[^a]: Post note.\n[^b]: Comment note.",
+            &options,
+        );
+        assert_eq!(
+            conversion.text,
+            "See docs [1].¹\n\n¹ Post note.\n\n[1] https://a.test"
+        );
+        assert_eq!(
+            conversion.first_comment.as_deref(),
+            Some("Also docs [1] and blog [2].¹\n\n¹ Comment note.\n\n[1] https://a.test\n[2] https://b.test")
+        );
+    }
+
+    #[test]
+    fn test_first_comment_marker() {
+        let conversion = convert_document(
+            "Post body.\n\n<!-- first-comment -->\n\n## Sources\n\nMore.",
+            &opts(),
+        );
+        assert_eq!(conversion.text, "Post body.");
+        assert_eq!(
+            conversion.first_comment,
+            Some(format!("{}\n\nMore.", to_bold("Sources")))
+        );
+    }
+
+    #[test]
+    fn test_first_comment_marker_next_to_spaces_and_links() {
+        let conversion = convert_document("Hi  <!-- first-comment --> **bold**", &opts());
+        assert_eq!(conversion.text, "Hi");
+        assert_eq!(conversion.first_comment, Some(to_bold("bold")));
+
+        let conversion = convert_document(
+            "Text <!-- first-comment --><https://x.io>{.preview}é",
+            &opts(),
+        );
+        assert_eq!(conversion.text, "Text\n\nhttps://x.io");
+        assert_eq!(conversion.first_comment.as_deref(), Some("é"));
+
+        let conversion = convert_document("Hi <!-- first-comment --><!-- x --> y", &opts());
+        assert_eq!(conversion.text, "Hi");
+        assert_eq!(conversion.first_comment.as_deref(), Some("y"));
+    }
+
+    #[test]
+    fn test_first_comment_marker_ignored_in_footnotes_and_tables() {
+        let mut options = opts();
+        options.footnote_heading = String::new();
+        let conversion = convert_document(
+            "Body[^1]\n\n[^1]: note <!-- first-comment --> more",
+            &options,
+        );
+        assert_eq!(conversion.text, "Body¹\n\n¹ note more");
+        assert_eq!(conversion.first_comment, None);
+
+        options.table_mode = TableMode::Bullets;
+        let conversion = convert_document(
+            "| a |\n|---|\n| x <!-- first-comment --> y |\n\nafter",
+            &options,
+        );
+        assert_eq!(conversion.text, "• x y\n\nafter");
+        assert_eq!(conversion.first_comment, None);
+    }
+
+    #[test]
+    fn test_no_first_comment() {
+        let conversion = convert_document("<!-- note -->\n\nJust a post.", &opts());
+        assert_eq!(conversion.text, "Just a post.");
+        assert_eq!(conversion.first_comment, None);
+    }
+
+    #[test]
+    fn test_links_moved_into_first_comment() {
+        let mut options = opts();
+        options.links_in_comment = true;
+        let conversion = convert_document(
+            "Read [the post](https://x.io/post) and [again](https://x.io/post), or [mail us](mailto:hi@x.io).\n\n<!-- first-comment -->\n\nThanks [team](https://team.io)!",
+            &options,
+        );
+        assert_eq!(
+            conversion.text,
+            "Read the post and again, or mail us (hi@x.io)."
+        );
+        assert_eq!(
+            conversion.first_comment.as_deref(),
+            Some("Thanks team (https://team.io)!\n\nthe post: https://x.io/post")
+        );
     }
 
     #[test]
@@ -367,7 +1983,23 @@ mod tests {
     #[test]
     fn test_inline_code() {
         let result = convert("Use `println!` macro", &opts());
-        assert!(result.contains("println!"));
+        assert_eq!(result, "Use 𝚙𝚛𝚒𝚗𝚝𝚕𝚗! macro");
+    }
+
+    #[test]
+    fn test_inline_code_wrapped() {
+        let mut options = opts();
+        options.inline_code = InlineCodeStyle::Guillemets;
+        assert_eq!(convert("Use `x`", &options), "Use ‹x›");
+        options.inline_code = InlineCodeStyle::Backticks;
+        assert_eq!(convert("Use `x`", &options), "Use `x`");
+    }
+
+    #[test]
+    fn test_inline_code_plain() {
+        let mut options = opts();
+        options.plain = true;
+        assert_eq!(convert("Use `println!`", &options), "Use println!");
     }
 
     #[test]
@@ -523,4 +2155,605 @@ mod tests {
         let result = convert(&input, &opts());
         assert_eq!(result, expected.trim_end_matches('\n'));
     }
+
+    #[test]
+    fn test_table_grid() {
+        let result = convert("| a | b |\n|---|---|\n| 1 | 2 |", &opts());
+        assert_eq!(
+            result,
+            "┌───┬───┐\n│ a │ b │\n├───┼───┤\n│ 1 │ 2 │\n└───┴───┘"
+        );
+    }
+
+    #[test]
+    fn test_table_bullets() {
+        let mut options = opts();
+        options.table_mode = TableMode::Bullets;
+        let result = convert("| a | b |\n|---|---|\n| 1 | 2 |\n| 3 | 4 |", &options);
+        assert_eq!(result, "• 1 · 2\n• 3 · 4");
+    }
+
+    #[test]
+    fn test_fixture_tables() {
+        let input = read_fixture("tests/fixtures/tables.md");
+        let expected = read_fixture("tests/fixtures/tables.txt");
+        let result = convert(&input, &opts());
+        assert_eq!(result, expected.trim_end_matches('\n'));
+    }
+
+    #[test]
+    fn test_fixture_tables_key_value() {
+        let input = read_fixture("tests/fixtures/tables.md");
+        let expected = read_fixture("tests/fixtures/tables_key_value.txt");
+        let mut options = opts();
+        options.table_mode = TableMode::KeyValue;
+        let result = convert(&input, &options);
+        assert_eq!(result, expected.trim_end_matches('\n'));
+    }
+
+    #[test]
+    fn test_strikethrough() {
+        let result = convert("~~old~~ new", &opts());
+        assert_eq!(result, format!("{} new", to_strikethrough("old")));
+    }
+
+    #[test]
+    fn test_bold_strikethrough_nested() {
+        let result = convert("**~~x~~**", &opts());
+        assert_eq!(result, to_strikethrough(&to_bold("x")));
+    }
+
+    #[test]
+    fn test_underline_html() {
+        let result = convert("<u>key</u> and <ins>*new*</ins>", &opts());
+        let expected = format!(
+            "{} and {}",
+            to_underline("key"),
+            to_underline(&crate::unicode::to_italic("new"))
+        );
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_strikethrough_plain() {
+        let mut options = opts();
+        options.plain = true;
+        let result = convert("~~old~~", &options);
+        assert_eq!(result, "old");
+    }
+
+    #[test]
+    fn test_task_list() {
+        let result = convert("- [x] shipped\n- [ ] docs\n- plain", &opts());
+        assert_eq!(result, "☑ shipped\n☐ docs\n• plain");
+    }
+
+    #[test]
+    fn test_task_list_style() {
+        let mut options = opts();
+        options.task_list_style = TaskListStyle::Emoji;
+        let result = convert("1. [x] shipped\n2. [ ] docs", &options);
+        assert_eq!(result, "1. ✅ shipped\n2. ⬜ docs");
+    }
+
+    #[test]
+    fn test_footnote_reference() {
+        let result = convert("Fact[^1].\n\n[^1]: Source.", &opts());
+        assert_eq!(result, "Fact¹.\n\n𝐍𝐨𝐭𝐞𝐬\n¹ Source.");
+    }
+
+    #[test]
+    fn test_footnote_plain() {
+        let mut options = opts();
+        options.plain = true;
+        options.footnote_heading = String::new();
+        let result = convert("Fact[^a].\n\n[^a]: Source.", &options);
+        assert_eq!(result, "Fact[1].\n\n[1] Source.");
+    }
+
+    #[test]
+    fn test_fixture_footnotes() {
+        let input = read_fixture("tests/fixtures/footnotes.md");
+        let expected = read_fixture("tests/fixtures/footnotes.txt");
+        let result = convert(&input, &opts());
+        assert_eq!(result, expected.trim_end_matches('\n'));
+    }
+
+    #[test]
+    fn test_fixture_callouts() {
+        let input = read_fixture("tests/fixtures/callouts.md");
+        let expected = read_fixture("tests/fixtures/callouts.txt");
+        let result = convert(&input, &opts());
+        assert_eq!(result, expected.trim_end_matches('\n'));
+    }
+
+    #[test]
+    fn test_fixture_smart_punctuation_locales() {
+        let input = read_fixture("tests/fixtures/smart_punctuation.md");
+        for (quote_style, path) in [
+            (QuoteStyle::English, "tests/fixtures/smart_punctuation.txt"),
+            (
+                QuoteStyle::French,
+                "tests/fixtures/smart_punctuation_fr.txt",
+            ),
+            (
+                QuoteStyle::German,
+                "tests/fixtures/smart_punctuation_de.txt",
+            ),
+            (
+                QuoteStyle::Japanese,
+                "tests/fixtures/smart_punctuation_ja.txt",
+            ),
+        ] {
+            let mut options = opts();
+            options.code_block_mode = CodeBlockMode::Text;
+            options.quote_style = quote_style;
+            let expected = read_fixture(path);
+            let result = convert(&input, &options);
+            assert_eq!(result, expected.trim_end_matches('\n'), "{}", path);
+        }
+    }
+
+    #[test]
+    fn test_smart_punctuation_disabled() {
+        let mut options = opts();
+        options.smart_punctuation = false;
+        let result = convert("\"a\" -- b...", &options);
+        assert_eq!(result, "\"a\" -- b...");
+    }
+
+    #[test]
+    fn test_rule_default() {
+        let result = convert("one\n\n---\n\ntwo", &opts());
+        assert_eq!(result, "one\n\n━━━━━━━━\n\ntwo");
+    }
+
+    #[test]
+    fn test_rule_custom() {
+        let mut options = opts();
+        options.rule = RuleStyle::Custom("* * *".to_string());
+        let result = convert("one\n\n***\n\ntwo", &options);
+        assert_eq!(result, "one\n\n* * *\n\ntwo");
+    }
+
+    #[test]
+    fn test_rule_blank() {
+        let mut options = opts();
+        options.rule = RuleStyle::Blank;
+        let result = convert("one\n\n---\n\ntwo", &options);
+        assert_eq!(result, "one\n\n\ntwo");
+    }
+
+    #[test]
+    fn test_inline_spans_off_by_default() {
+        let result = convert("{script}[x] ==y==", &opts());
+        assert_eq!(result, "{script}[x] ==y==");
+    }
+
+    #[test]
+    fn test_inline_span_family() {
+        let mut options = opts();
+        options.inline_spans = true;
+        let result = convert("a {double-struck}[Rust] b", &options);
+        assert_eq!(result, "a ℝ𝕦𝕤𝕥 b");
+    }
+
+    #[test]
+    fn test_inline_span_nests_with_strong() {
+        let mut options = opts();
+        options.inline_spans = true;
+        let result = convert("{sans}[a **b**] ==c *d*==", &options);
+        assert_eq!(result, "𝖺 𝗯 𝗰 𝙙");
+    }
+
+    #[test]
+    fn test_inline_span_unbalanced_close_is_literal() {
+        let mut options = opts();
+        options.inline_spans = true;
+        let result = convert("x] and a == b ==c==", &options);
+        assert_eq!(result, "x] and a == b 𝗰");
+    }
+
+    #[test]
+    fn test_inline_span_unclosed_does_not_leak() {
+        let mut options = opts();
+        options.inline_spans = true;
+        let result = convert("{bold}[open\n\nnext", &options);
+        assert_eq!(result, "𝐨𝐩𝐞𝐧\n\nnext");
+    }
+
+    #[test]
+    fn test_html_inline_styles() {
+        let result = convert(
+            "Press <kbd>Ctrl</kbd>, <b>then</b> <i>go</i><br>H<sub>2</sub>O",
+            &opts(),
+        );
+        assert_eq!(
+            result,
+            format!(
+                "Press 「Ctrl」, {} {}\nH₂O",
+                to_bold("then"),
+                crate::unicode::to_italic("go")
+            )
+        );
+    }
+
+    #[test]
+    fn test_html_underline() {
+        let result = convert("<u>hi</u> there", &opts());
+        assert_eq!(result, format!("{} there", to_underline("hi")));
+    }
+
+    #[test]
+    fn test_html_details_flattened() {
+        let input = "<details>\n<summary>More</summary>\n\nHidden text\n\n</details>\n\nAfter";
+        let result = convert(input, &opts());
+        assert_eq!(
+            result,
+            format!("{}\n\nHidden text\n\nAfter", to_bold("More"))
+        );
+    }
+
+    #[test]
+    fn test_html_comments_stripped() {
+        let input = "Before <!-- inline --> after\n\n<!--\nblock\ncomment\n-->\n\nEnd";
+        let result = convert(input, &opts());
+        assert_eq!(result, "Before after\n\nEnd");
+    }
+
+    #[test]
+    fn test_html_unmapped_tags_warn() {
+        let conversion = convert_document(
+            "<span class=\"x\">a</span> <mark>b</mark> <span>c</span>",
+            &opts(),
+        );
+        assert_eq!(conversion.text, "a b c");
+        assert_eq!(
+            conversion.warnings,
+            vec!["HTML tags not converted: <span>, <mark>".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_hashtags_mentions_and_urls_stay_unstyled() {
+        let result = convert(
+            "**Learn #RustLang with @Ferris at https://rust-lang.org.**",
+            &opts(),
+        );
+        assert_eq!(
+            result,
+            format!(
+                "{}#RustLang{}@Ferris{}https://rust-lang.org{}",
+                to_bold("Learn "),
+                to_bold(" with "),
+                to_bold(" at "),
+                to_bold(".")
+            )
+        );
+    }
+
+    #[test]
+    fn test_entities_unstyled_in_html_and_callout_titles() {
+        let result = convert(
+            "Join <b>#RustLang</b>\n\n> [!tip] Ask @Ferris\n> Hi.",
+            &opts(),
+        );
+        assert_eq!(
+            result,
+            format!("Join #RustLang\n\n💡 {}@Ferris\nHi.", to_bold("Ask "))
+        );
+    }
+
+    #[test]
+    fn test_hashtag_keeps_case_in_uppercase_heading() {
+        let mut options = opts();
+        options
+            .heading_styles
+            .insert(HeadingLevel::H2, HeadingStyle::parse("bold,upper").unwrap());
+        let result = convert("## love #RustLang", &options);
+        assert_eq!(result, format!("{}#RustLang", to_bold("LOVE ")));
+    }
+
+    #[test]
+    fn test_accidental_hashtag_warns() {
+        let conversion = convert_document("My #1 tip, #1 again, #Rust and C#", &opts());
+        assert_eq!(conversion.text, "My #1 tip, #1 again, #Rust and C#");
+        assert_eq!(
+            conversion.warnings,
+            vec!["LinkedIn will turn these into hashtag links: #1".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_overlapping_span_and_emphasis_do_not_leak() {
+        let mut options = opts();
+        options.inline_spans = true;
+        let result = convert("*a {bold}[b* c]\n\nnext para", &options);
+        assert_eq!(
+            result,
+            format!(
+                "{}{} c]\n\nnext para",
+                crate::unicode::to_italic("a "),
+                to_bold("b")
+            )
+        );
+    }
+
+    #[test]
+    fn test_html_anchor_is_a_link() {
+        let conversion = convert_document(
+            "See <a href=\"https://x.io/?fbclid=1\">site</a> and <a href='https://x.io'>https://x.io</a>.",
+            &opts(),
+        );
+        assert_eq!(
+            conversion.text,
+            "See site (https://x.io/) and https://x.io."
+        );
+        assert!(conversion.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_html_anchor_closes_with_its_block() {
+        let result = convert("<a href=\"https://a.io\">é\n\nmore</a>", &opts());
+        assert_eq!(result, "é (https://a.io)\n\nmore");
+
+        let mut options = opts();
+        options.table_mode = TableMode::Bullets;
+        let result = convert(
+            "| a |\n|---|\n| x <a href=\"https://a.io\">é\n</a>\n",
+            &options,
+        );
+        assert_eq!(result, "• x é (https://a.io)\n•");
+        let result = convert(
+            "| a |\n|---|\n| <a href=\"https://a.io\">é |\n\nmore</a>\n",
+            &options,
+        );
+        assert_eq!(result, "• é (https://a.io)\n\nmore");
+    }
+
+    #[test]
+    fn test_html_self_closing_tags() {
+        let result = convert("A<b/> b<i /> c<br/>d", &opts());
+        assert_eq!(result, "A b c\nd");
+    }
+
+    #[test]
+    fn test_html_unclosed_tag_does_not_leak() {
+        let result = convert("<b>open\n\nnext", &opts());
+        assert_eq!(result, format!("{}\n\nnext", to_bold("open")));
+    }
+
+    #[test]
+    fn test_single_tilde_is_strikethrough_by_default() {
+        let result = convert("~old~ new", &opts());
+        assert_eq!(result, format!("{} new", to_strikethrough("old")));
+    }
+
+    #[test]
+    fn test_superscript_and_subscript() {
+        let mut options = opts();
+        options.subscript = true;
+        let result = convert("H~2~O and x^2^ on the 2^nd^ try, ~~gone~~", &options);
+        assert_eq!(
+            result,
+            format!("H₂O and x² on the 2ⁿᵈ try, {}", to_strikethrough("gone"))
+        );
+    }
+
+    #[test]
+    fn test_script_emoticons_stay_literal() {
+        let mut options = opts();
+        options.subscript = true;
+        let result = convert("Thanks ^-^ and ~_~, x^-1^", &options);
+        assert_eq!(result, "Thanks ^-^ and ~_~, x⁻¹");
+    }
+
+    #[test]
+    fn test_superscript_fallback() {
+        let result = convert("<sup>Q&A</sup> and e^iπ^", &opts());
+        assert_eq!(result, "^(Q&A) and e^(iπ)");
+    }
+
+    #[test]
+    fn test_superscript_plain() {
+        let mut options = opts();
+        options.plain = true;
+        options.subscript = true;
+        let result = convert("x^2^ and CO~2~", &options);
+        assert_eq!(result, "x^(2) and CO_(2)");
+    }
+
+    #[test]
+    fn test_inline_math() {
+        let mut options = opts();
+        options.math = true;
+        let result = convert("Einstein: $E = mc^2$, costs $5 or $10", &options);
+        assert_eq!(result, "Einstein: E = mc², costs $5 or $10");
+    }
+
+    #[test]
+    fn test_currency_is_not_math_by_default() {
+        let result = convert("Plans cost $5-$10 per month. Pay $20/$30", &opts());
+        assert_eq!(result, "Plans cost $5-$10 per month. Pay $20/$30");
+    }
+
+    #[test]
+    fn test_display_math() {
+        let mut options = opts();
+        options.math = true;
+        let result = convert("Sum:\n$$\\sum_{i=1}^n x_i$$\nDone", &options);
+        assert_eq!(result, "Sum:\n∑ᵢ₌₁ⁿ xᵢ\nDone");
+    }
+
+    #[test]
+    fn test_math_unsupported_warning() {
+        let mut options = opts();
+        options.math = true;
+        let conversion = convert_document("$\\oint \\widgetize{x}$", &options);
+        assert_eq!(conversion.text, "∮ widgetizex");
+        assert_eq!(
+            conversion.warnings,
+            vec!["LaTeX commands not converted: \\widgetize".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_emoji_shortcodes() {
+        let input = "# Launch :rocket:\n\n:tada: `:tada:`\n\n```\n:tada:\n```";
+        let mut options = opts();
+        options.code_block_mode = CodeBlockMode::Text;
+        options.inline_code = InlineCodeStyle::Backticks;
+        let result = convert(input, &options);
+        assert_eq!(
+            result,
+            format!(
+                "{} 🚀\n━━━━━━━━\n\n🎉 `:tada:`\n\n:tada:",
+                to_bold("Launch")
+            )
+        );
+    }
+
+    #[test]
+    fn test_emoji_shortcodes_disabled() {
+        let mut options = opts();
+        options.emoji_shortcodes = false;
+        assert_eq!(convert(":tada:", &options), ":tada:");
+    }
+
+    #[test]
+    fn test_gfm_alert() {
+        let result = convert("> [!TIP]\n> Ship *small* PRs.", &opts());
+        assert_eq!(
+            result,
+            format!(
+                "💡 {}\nShip {} PRs.",
+                to_bold("Tip"),
+                crate::unicode::to_italic("small")
+            )
+        );
+    }
+
+    #[test]
+    fn test_obsidian_callout_with_title() {
+        let result = convert("> [!faq]- Why **now**?\n> Because.", &opts());
+        assert_eq!(result, format!("❓ {}\nBecause.", to_bold("Why now?")));
+    }
+
+    #[test]
+    fn test_callout_title_starting_with_markup() {
+        let result = convert("> [!NOTE] **Bold** title\n> Body.", &opts());
+        assert_eq!(result, format!("📝 {}\nBody.", to_bold("Bold title")));
+
+        let mut options = opts();
+        options.plain = true;
+        options.inline_code = InlineCodeStyle::Backticks;
+        let result = convert(
+            "> [!TIP] `cargo fmt` first\n> Body.\n\n> [!TIP] [Docs](https://x.io)\n> Body.\n\n> [!TIP]\n> Body.",
+            &options,
+        );
+        assert_eq!(
+            result,
+            "💡 `cargo fmt` first\nBody.\n\n💡 Docs (https://x.io)\nBody.\n\n💡 Tip\nBody."
+        );
+    }
+
+    #[test]
+    fn test_callout_icon_override() {
+        let mut options = opts();
+        options.plain = true;
+        options
+            .callout_icons
+            .insert("warning".to_string(), "🔥".to_string());
+        let result = convert("> [!WARNING]\n> Hot.\n\n> [!custom]\n> Other.", &options);
+        assert_eq!(result, "🔥 Warning\nHot.\n\n📝 Custom\nOther.");
+    }
+
+    #[test]
+    fn test_plain_blockquote_still_italic() {
+        let result = convert("> [link] text", &opts());
+        assert_eq!(result, crate::unicode::to_italic("[link] text"));
+    }
+
+    #[test]
+    fn test_definition_list() {
+        let input = "Intro\n\nAPI\n: Application programming interface\n: A contract\n\nSLA\n\n: Service level agreement\n\n  Usually yearly\n\nAfter";
+        let result = convert(input, &opts());
+        assert_eq!(
+            result,
+            format!(
+                "Intro\n\n{}\n– Application programming interface\n– A contract\n\n{}\n– Service level agreement\n  Usually yearly\n\nAfter",
+                to_bold("API"),
+                to_bold("SLA")
+            )
+        );
+    }
+
+    #[test]
+    fn test_definition_list_indent_and_family() {
+        let mut options = opts();
+        options.definition_layout = DefinitionLayout::Indent;
+        options.definition_term_family = StyleFamily::SansBold;
+        let result = convert("Term\n: Meaning", &options);
+        assert_eq!(result, "𝗧𝗲𝗿𝗺\n    Meaning");
+    }
+
+    #[test]
+    fn test_definition_list_in_list_item() {
+        let result = convert("- item\n\n  Term\n  : def\n- next", &opts());
+        assert_eq!(
+            result,
+            format!("• item\n  {}\n  – def\n• next", to_bold("Term"))
+        );
+    }
+
+    #[test]
+    fn test_wikilinks_resolved() {
+        let mut options = opts();
+        options.wikilink_template = Some("https://notes.example.com/{slug}".to_string());
+        options.wikilink_urls.insert(
+            "Roadmap".to_string(),
+            "https://example.com/plan".to_string(),
+        );
+        let result = convert("See [[Release Notes|the notes]] and [[Roadmap]].", &options);
+        assert_eq!(
+            result,
+            "See the notes (https://notes.example.com/release-notes) and Roadmap (https://example.com/plan)."
+        );
+    }
+
+    #[test]
+    fn test_wikilinks_unresolved_warn() {
+        let conversion = convert_document(
+            "See [[Private|my notes]] and [[Other]], then [[Private]] again.",
+            &opts(),
+        );
+        assert_eq!(
+            conversion.text,
+            "See my notes and Other, then Private again."
+        );
+        assert_eq!(
+            conversion.warnings,
+            vec![
+                "Unresolved wikilink: [[Private]]".to_string(),
+                "Unresolved wikilink: [[Other]]".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hidden_heading_registers_no_references() {
+        let mut options = opts();
+        options.link_style = LinkStyle::References;
+        options.footnote_heading = String::new();
+        let input = "## Draft [spec](https://a.test)[^a] {.hide}\n\nBody [site](https://b.test)[^b]\n\n[^a]: Hidden\n[^b]: Shown";
+        let result = convert(input, &options);
+        assert_eq!(result, "Body site [1]¹\n\n¹ Shown\n\n[1] https://b.test");
+    }
+
+    #[test]
+    fn test_heading_attributes() {
+        let input = "## Launch {.sans-bold .emoji-rocket}\n\n## Draft notes {.hide}\n\nBody\n\n## Plain {#anchor}";
+        let result = convert(input, &opts());
+        assert_eq!(result, format!("🚀 𝗟𝗮𝘂𝗻𝗰𝗵\n\nBody\n\n{}", to_bold("Plain")));
+    }
 }
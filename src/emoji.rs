@@ -0,0 +1,94 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// Replace `:shortcode:` with its emoji.
+///
+/// `custom` entries are checked first (keys may be written with or without
+/// the surrounding colons), then the built-in GitHub/Slack table. Unknown
+/// shortcodes and colons that don't form one, like `10:30`, are left alone.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use mdtolinkedin::emoji::expand_shortcodes;
+/// assert_eq!(expand_shortcodes("Shipped :rocket:", &HashMap::new()), "Shipped 🚀");
+/// ```
+pub fn expand_shortcodes<'a>(text: &'a str, custom: &HashMap<String, String>) -> Cow<'a, str> {
+    if !text.contains(':') {
+        return Cow::Borrowed(text);
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(':') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let name_len = after
+            .find(|c: char| !is_shortcode_char(c))
+            .unwrap_or(after.len());
+        if name_len > 0 && after[name_len..].starts_with(':') {
+            if let Some(emoji) = shortcode(&after[..name_len], custom) {
+                out.push_str(emoji);
+                rest = &after[name_len + 1..];
+                continue;
+            }
+        }
+        // Not a shortcode; the closing colon may still open the next one
+        out.push(':');
+        rest = after;
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
+}
+
+fn is_shortcode_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '+' | '-')
+}
+
+/// Emoji for a shortcode name (without colons), checking `custom` first.
+pub fn shortcode<'a>(name: &str, custom: &'a HashMap<String, String>) -> Option<&'a str> {
+    if let Some(emoji) = custom
+        .get(name)
+        .or_else(|| custom.get(&format!(":{}:", name)))
+    {
+        return Some(emoji);
+    }
+    emojis::get_by_shortcode(name).map(|emoji| emoji.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_shortcodes() {
+        let custom = HashMap::new();
+        assert_eq!(
+            expand_shortcodes(":tada: done :white_check_mark: :+1:", &custom),
+            "🎉 done ✅ 👍"
+        );
+    }
+
+    #[test]
+    fn test_unknown_and_clock_times_untouched() {
+        let custom = HashMap::new();
+        assert_eq!(
+            expand_shortcodes("at 10:30:00 see :not_an_emoji:", &custom),
+            "at 10:30:00 see :not_an_emoji:"
+        );
+    }
+
+    #[test]
+    fn test_adjacent_colon_starts_next_shortcode() {
+        let custom = HashMap::new();
+        assert_eq!(expand_shortcodes("ratio:zz:rocket:", &custom), "ratio:zz🚀");
+    }
+
+    #[test]
+    fn test_custom_mapping_overrides() {
+        let mut custom = HashMap::new();
+        custom.insert("rocket".to_string(), "🛸".to_string());
+        custom.insert(":ship-it:".to_string(), "🚢".to_string());
+        assert_eq!(expand_shortcodes(":rocket: :ship-it:", &custom), "🛸 🚢");
+    }
+}
@@ -0,0 +1,146 @@
+/// A piece of text split around the things LinkedIn links by itself.
+///
+/// URLs, `#hashtags` and `@mentions` only work as plain characters, so the
+/// converter leaves them unstyled and styles the `Text` around them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Segment<'a> {
+    Text(&'a str),
+    Url(&'a str),
+    Hashtag(&'a str),
+    Mention(&'a str),
+}
+
+/// Split `text` into plain text and URLs, hashtags and mentions.
+///
+/// Each one must start a word, so `C#`, `issue#4` and `me@example.com` stay
+/// text. Trailing sentence punctuation is not part of the match.
+///
+/// # Example
+/// ```
+/// use mdtolinkedin::entities::{split, Segment};
+/// assert_eq!(
+///     split("Hi @ana, see #RustLang."),
+///     vec![
+///         Segment::Text("Hi "),
+///         Segment::Mention("@ana"),
+///         Segment::Text(", see "),
+///         Segment::Hashtag("#RustLang"),
+///         Segment::Text("."),
+///     ]
+/// );
+/// ```
+pub fn split(text: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    let mut i = 0;
+
+    while i < text.len() {
+        let rest = &text[i..];
+        let Some(c) = rest.chars().next() else {
+            break;
+        };
+        let word_start = text[..i]
+            .chars()
+            .next_back()
+            .is_none_or(|prev| !prev.is_alphanumeric() && !matches!(prev, '_' | '&' | '#' | '@'));
+        let len = if !word_start {
+            0
+        } else if ["https://", "http://", "www."]
+            .iter()
+            .any(|scheme| rest.starts_with(scheme))
+        {
+            url_len(rest)
+        } else if matches!(c, '#' | '@') {
+            tag_len(rest)
+        } else {
+            0
+        };
+        if len <= 1 {
+            i += c.len_utf8();
+            continue;
+        }
+        if start < i {
+            segments.push(Segment::Text(&text[start..i]));
+        }
+        let matched = &text[i..i + len];
+        segments.push(match c {
+            '#' => Segment::Hashtag(matched),
+            '@' => Segment::Mention(matched),
+            _ => Segment::Url(matched),
+        });
+        i += len;
+        start = i;
+    }
+    if start < text.len() {
+        segments.push(Segment::Text(&text[start..]));
+    }
+    segments
+}
+
+/// A hashtag with no letters, like the `#1` in "#1 tip", which LinkedIn links
+/// all the same.
+pub fn is_accidental_hashtag(tag: &str) -> bool {
+    !tag.chars().any(char::is_alphabetic)
+}
+
+/// Length of the URL at the start of `text`: up to whitespace, without
+/// trailing punctuation or a `)` that closes an outer parenthesis.
+fn url_len(text: &str) -> usize {
+    let mut url = text
+        .find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"'))
+        .map_or(text, |end| &text[..end]);
+    loop {
+        let trimmed = url.trim_end_matches(['.', ',', ';', ':', '!', '?', '\'']);
+        let trimmed = match trimmed.strip_suffix(')') {
+            Some(inner) if trimmed.matches('(').count() < trimmed.matches(')').count() => inner,
+            _ => trimmed,
+        };
+        if trimmed.len() == url.len() {
+            return url.len();
+        }
+        url = trimmed;
+    }
+}
+
+/// Length of `#tag` or `@name` at the start of `text`, marker included.
+/// Mentions may also contain `.` and `-`, as in `@jane.doe`.
+fn tag_len(text: &str) -> usize {
+    let mention = text.starts_with('@');
+    let body = &text[1..];
+    let end = body
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || (mention && matches!(c, '.' | '-'))))
+        .unwrap_or(body.len());
+    1 + body[..end].trim_end_matches(['.', '-']).len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_url_keeps_trailing_punctuation_out() {
+        assert_eq!(
+            split("Docs (https://example.com/a_(b)). Next"),
+            vec![
+                Segment::Text("Docs ("),
+                Segment::Url("https://example.com/a_(b)"),
+                Segment::Text("). Next"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_ignores_mid_word_markers() {
+        assert_eq!(
+            split("C# and me@example.com and &#35;"),
+            vec![Segment::Text("C# and me@example.com and &#35;")]
+        );
+        assert_eq!(split("# alone"), vec![Segment::Text("# alone")]);
+    }
+
+    #[test]
+    fn test_accidental_hashtag() {
+        assert!(is_accidental_hashtag("#1"));
+        assert!(!is_accidental_hashtag("#2024Goals"));
+    }
+}
@@ -0,0 +1,45 @@
+/// Line printed between the post and its first comment in text output.
+pub const DIVIDER: &str = "───── 💬 First comment ─────";
+
+/// LinkedIn's character limit for a comment.
+pub const COMMENT_LIMIT: usize = 1250;
+
+/// Whether raw HTML is the `<!-- first-comment -->` marker.
+///
+/// # Example
+/// ```
+/// use mdtolinkedin::first_comment::is_marker;
+/// assert!(is_marker("<!--  First Comment -->\n"));
+/// assert!(!is_marker("<!-- draft -->"));
+/// ```
+pub fn is_marker(html: &str) -> bool {
+    html.trim()
+        .strip_prefix("<!--")
+        .and_then(|rest| rest.strip_suffix("-->"))
+        .is_some_and(is_label)
+}
+
+/// Whether a heading's text opens the first-comment section, e.g. `## First comment`.
+pub fn is_heading(text: &str) -> bool {
+    is_label(text)
+}
+
+fn is_label(text: &str) -> bool {
+    let words: Vec<&str> = text.split(['-', ' ']).filter(|w| !w.is_empty()).collect();
+    words.len() == 2
+        && words[0].eq_ignore_ascii_case("first")
+        && words[1].eq_ignore_ascii_case("comment")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heading_label() {
+        assert!(is_heading("First comment"));
+        assert!(is_heading(" first-comment "));
+        assert!(!is_heading("First comments"));
+        assert!(!is_heading("The first comment"));
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use crate::unicode::{to_bold, to_italic, to_superscript_digits};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FootnoteHeadingStyle {
+    Bold,
+    Italic,
+    Plain,
+}
+
+/// Footnote references and definitions collected while converting.
+///
+/// References are numbered in the order they first appear; definitions are
+/// stored by label and emitted in that order as a trailing notes block.
+#[derive(Debug, Default)]
+pub struct Footnotes {
+    numbers: HashMap<String, usize>,
+    order: Vec<String>,
+    definitions: HashMap<String, String>,
+}
+
+impl Footnotes {
+    /// Number for a reference, assigning the next one on first use.
+    pub fn reference(&mut self, label: &str) -> usize {
+        if let Some(number) = self.numbers.get(label) {
+            return *number;
+        }
+        self.order.push(label.to_string());
+        let number = self.order.len();
+        self.numbers.insert(label.to_string(), number);
+        number
+    }
+
+    pub fn define(&mut self, label: &str, text: String) {
+        self.definitions.insert(label.to_string(), text);
+    }
+
+    /// Render the notes block, or `None` when nothing referenced a definition.
+    pub fn render(
+        &self,
+        heading: &str,
+        style: FootnoteHeadingStyle,
+        plain: bool,
+    ) -> Option<String> {
+        let lines: Vec<String> = self
+            .order
+            .iter()
+            .enumerate()
+            .filter_map(|(i, label)| {
+                let text = self.definitions.get(label)?;
+                Some(format!("{} {}", marker(i + 1, plain), text))
+            })
+            .collect();
+        if lines.is_empty() {
+            return None;
+        }
+
+        let mut out = String::new();
+        if !heading.is_empty() {
+            let heading = match style {
+                _ if plain => heading.to_string(),
+                FootnoteHeadingStyle::Bold => to_bold(heading),
+                FootnoteHeadingStyle::Italic => to_italic(heading),
+                FootnoteHeadingStyle::Plain => heading.to_string(),
+            };
+            out.push_str(&heading);
+            out.push('\n');
+        }
+        out.push_str(&lines.join("\n"));
+        Some(out)
+    }
+}
+
+/// Superscript number for a reference, or `[n]` in plain mode.
+pub fn marker(number: usize, plain: bool) -> String {
+    if plain {
+        format!("[{}]", number)
+    } else {
+        to_superscript_digits(&number.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_numbers_are_stable() {
+        let mut notes = Footnotes::default();
+        assert_eq!(notes.reference("b"), 1);
+        assert_eq!(notes.reference("a"), 2);
+        assert_eq!(notes.reference("b"), 1);
+    }
+
+    #[test]
+    fn test_render_in_reference_order() {
+        let mut notes = Footnotes::default();
+        notes.define("a", "First defined".to_string());
+        notes.define("b", "Second defined".to_string());
+        notes.reference("b");
+        notes.reference("a");
+        let rendered = notes
+            .render("Notes", FootnoteHeadingStyle::Plain, false)
+            .unwrap();
+        assert_eq!(rendered, "Notes\n¹ Second defined\n² First defined");
+    }
+
+    #[test]
+    fn test_render_without_references() {
+        let mut notes = Footnotes::default();
+        notes.define("a", "Unused".to_string());
+        assert!(notes
+            .render("Notes", FootnoteHeadingStyle::Bold, false)
+            .is_none());
+    }
+}
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+/// Top-level `key: value` pairs of a YAML front matter block.
+///
+/// Only plain scalars are read: surrounding quotes are removed, and nested
+/// mappings, lists and comments are skipped.
+///
+/// # Example
+/// ```
+/// use mdtolinkedin::front_matter::parse;
+/// let fields = parse("title: \"Launch day\"\ntags:\n  - rust\nutm_campaign: spring # Q2\n");
+/// assert_eq!(fields["title"], "Launch day");
+/// assert_eq!(fields["utm_campaign"], "spring");
+/// assert!(!fields.contains_key("tags"));
+/// ```
+pub fn parse(yaml: &str) -> HashMap<String, String> {
+    let mut fields = HashMap::new();
+    for line in yaml.lines() {
+        if line.starts_with(char::is_whitespace) || line.starts_with(['#', '-']) {
+            continue;
+        }
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = scalar(value.trim());
+        if !key.trim().is_empty() && !value.is_empty() {
+            fields.insert(key.trim().to_string(), value.to_string());
+        }
+    }
+    fields
+}
+
+fn scalar(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = value
+            .strip_prefix(quote)
+            .and_then(|rest| rest.split_once(quote))
+            .map(|(inner, _)| inner)
+        {
+            return inner;
+        }
+    }
+    match value.find(" #") {
+        Some(comment) => value[..comment].trim_end(),
+        None => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quoted_values_keep_hashes_and_colons() {
+        let fields = parse("title: 'Rust #1: why'\nempty:\n");
+        assert_eq!(fields["title"], "Rust #1: why");
+        assert!(!fields.contains_key("empty"));
+    }
+}
@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+
+use pulldown_cmark::{CowStr, HeadingLevel};
+
+use crate::emoji;
+use crate::unicode::StyleFamily;
+
+/// Heading classes that leave the heading out of the output.
+const HIDE_CLASSES: [&str; 2] = ["hide", "no-linkedin"];
+
+/// Class prefix that turns a shortcode into the heading's emoji prefix: `.emoji-rocket`.
+const EMOJI_CLASS_PREFIX: &str = "emoji-";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeadingCase {
+    AsIs,
+    Upper,
+    Title,
+}
+
+/// How a single heading level is rendered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeadingStyle {
+    pub family: StyleFamily,
+    pub case: HeadingCase,
+    /// Emoji or symbol placed before the heading text.
+    pub prefix: Option<String>,
+    /// Character repeated under the heading to the width of the heading line.
+    pub underline: Option<char>,
+}
+
+impl HeadingStyle {
+    pub fn new(family: StyleFamily) -> Self {
+        Self {
+            family,
+            case: HeadingCase::AsIs,
+            prefix: None,
+            underline: None,
+        }
+    }
+
+    /// Parse a comma-separated spec such as `bold,upper,prefix=🚀,underline=━`.
+    ///
+    /// The first recognised family name sets the family; `upper` and `title`
+    /// set the case transform.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut style = HeadingStyle::new(StyleFamily::Bold);
+        for part in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            style.apply_option(part)?;
+        }
+        Ok(style)
+    }
+
+    fn apply_option(&mut self, part: &str) -> Result<(), String> {
+        if let Some(prefix) = part.strip_prefix("prefix=") {
+            self.prefix = Some(prefix.to_string());
+        } else if let Some(underline) = part.strip_prefix("underline=") {
+            let mut chars = underline.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => self.underline = Some(c),
+                _ => return Err(format!("underline must be one character: {}", underline)),
+            }
+        } else if part == "upper" {
+            self.case = HeadingCase::Upper;
+        } else if part == "title" {
+            self.case = HeadingCase::Title;
+        } else if let Some(family) = StyleFamily::from_name(part) {
+            self.family = family;
+        } else {
+            return Err(format!("unknown heading style option: {}", part));
+        }
+        Ok(())
+    }
+
+    /// This style adjusted by a heading's `{.class key=value}` attributes, or
+    /// `None` when a `.hide` / `.no-linkedin` class drops the heading.
+    ///
+    /// Classes may name a family or case (`.script`, `.upper`), or an emoji
+    /// prefix (`.🚀` or `.emoji-` and a shortcode, as in `.emoji-rocket`); `prefix=` and
+    /// `underline=` attributes work as in [`HeadingStyle::parse`]. Anything
+    /// else is left for other renderers and ignored.
+    pub fn with_attributes(
+        &self,
+        classes: &[CowStr<'_>],
+        attrs: &[(CowStr<'_>, Option<CowStr<'_>>)],
+        emoji_map: &HashMap<String, String>,
+    ) -> Option<HeadingStyle> {
+        let mut style = self.clone();
+        for class in classes {
+            let class: &str = class;
+            if HIDE_CLASSES.contains(&class) {
+                return None;
+            }
+            if style.apply_option(class).is_ok() {
+                continue;
+            }
+            let icon = match class.strip_prefix(EMOJI_CLASS_PREFIX) {
+                Some(name) => emoji::shortcode(name, emoji_map),
+                None => emojis::get(class).map(|_| class),
+            };
+            if let Some(icon) = icon {
+                style.prefix = Some(icon.to_string());
+            }
+        }
+        for (key, value) in attrs {
+            if let Some(value) = value {
+                let _ = style.apply_option(&format!("{}={}", key, value));
+            }
+        }
+        Some(style)
+    }
+
+    pub fn apply_case(&self, text: &str, at_word_start: bool) -> String {
+        match self.case {
+            HeadingCase::AsIs => text.to_string(),
+            HeadingCase::Upper => text.to_uppercase(),
+            HeadingCase::Title => to_title_case(text, at_word_start),
+        }
+    }
+}
+
+/// Default styles: H1 bold and underlined, H2 bold, H3 bold italic, H4–H6 italic.
+pub fn default_heading_styles() -> HashMap<HeadingLevel, HeadingStyle> {
+    let mut styles = HashMap::with_capacity(6);
+    styles.insert(
+        HeadingLevel::H1,
+        HeadingStyle {
+            underline: Some('━'),
+            ..HeadingStyle::new(StyleFamily::Bold)
+        },
+    );
+    styles.insert(HeadingLevel::H2, HeadingStyle::new(StyleFamily::Bold));
+    styles.insert(HeadingLevel::H3, HeadingStyle::new(StyleFamily::BoldItalic));
+    for level in [HeadingLevel::H4, HeadingLevel::H5, HeadingLevel::H6] {
+        styles.insert(level, HeadingStyle::new(StyleFamily::Italic));
+    }
+    styles
+}
+
+/// Parse a heading level name (`h1`–`h6` or `1`–`6`).
+pub fn parse_level(name: &str) -> Option<HeadingLevel> {
+    match name.trim_start_matches(['h', 'H']) {
+        "1" => Some(HeadingLevel::H1),
+        "2" => Some(HeadingLevel::H2),
+        "3" => Some(HeadingLevel::H3),
+        "4" => Some(HeadingLevel::H4),
+        "5" => Some(HeadingLevel::H5),
+        "6" => Some(HeadingLevel::H6),
+        _ => None,
+    }
+}
+
+/// Uppercase the first letter of each word. `at_word_start` says whether the
+/// text continues a word from a previous chunk of the same heading.
+fn to_title_case(text: &str, at_word_start: bool) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut word_start = at_word_start;
+    for c in text.chars() {
+        if word_start && c.is_alphabetic() {
+            out.extend(c.to_uppercase());
+        } else {
+            out.push(c);
+        }
+        word_start = c.is_whitespace() || c == '-';
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_spec() {
+        let style = HeadingStyle::parse("italic,upper,prefix=🚀,underline=─").unwrap();
+        assert_eq!(style.family, StyleFamily::Italic);
+        assert_eq!(style.case, HeadingCase::Upper);
+        assert_eq!(style.prefix.as_deref(), Some("🚀"));
+        assert_eq!(style.underline, Some('─'));
+    }
+
+    #[test]
+    fn test_parse_spec_rejects_unknown() {
+        assert!(HeadingStyle::parse("bold,shouting").is_err());
+        assert!(HeadingStyle::parse("underline=ab").is_err());
+    }
+
+    #[test]
+    fn test_with_attributes() {
+        let base = HeadingStyle::new(StyleFamily::Bold);
+        let classes = [
+            CowStr::from("script"),
+            CowStr::from("emoji-rocket"),
+            CowStr::from("wide"),
+        ];
+        let attrs = [(CowStr::from("underline"), Some(CowStr::from("═")))];
+        let style = base
+            .with_attributes(&classes, &attrs, &HashMap::new())
+            .unwrap();
+        assert_eq!(style.family, StyleFamily::Script);
+        assert_eq!(style.prefix.as_deref(), Some("🚀"));
+        assert_eq!(style.underline, Some('═'));
+    }
+
+    #[test]
+    fn test_with_attributes_ignores_bare_shortcodes() {
+        let base = HeadingStyle::new(StyleFamily::Bold);
+        let classes = [
+            CowStr::from("new"),
+            CowStr::from("warning"),
+            CowStr::from("café"),
+        ];
+        let style = base
+            .with_attributes(&classes, &[], &HashMap::new())
+            .unwrap();
+        assert_eq!(style.prefix, None);
+        let classes = [CowStr::from("✨")];
+        let style = base
+            .with_attributes(&classes, &[], &HashMap::new())
+            .unwrap();
+        assert_eq!(style.prefix.as_deref(), Some("✨"));
+    }
+
+    #[test]
+    fn test_with_attributes_hide() {
+        let base = HeadingStyle::new(StyleFamily::Bold);
+        let classes = [CowStr::from("upper"), CowStr::from("hide")];
+        assert!(base
+            .with_attributes(&classes, &[], &HashMap::new())
+            .is_none());
+    }
+
+    #[test]
+    fn test_title_case() {
+        assert_eq!(to_title_case("top ten tips", true), "Top Ten Tips");
+        assert_eq!(to_title_case("ing more", false), "ing More");
+    }
+
+    #[test]
+    fn test_parse_level() {
+        assert_eq!(parse_level("h2"), Some(HeadingLevel::H2));
+        assert_eq!(parse_level("3"), Some(HeadingLevel::H3));
+        assert_eq!(parse_level("h7"), None);
+    }
+}
@@ -0,0 +1,183 @@
+/// A piece of raw HTML from `Event::Html` or `Event::InlineHtml`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HtmlToken<'a> {
+    Text(&'a str),
+    /// Opening tag, lowercased, attributes dropped.
+    Open(String),
+    Close(String),
+    /// Self-closing tag such as `<br/>`, which never has a matching close.
+    SelfClosing(String),
+    /// Opening `<a>` with its `href`, closed by `Close("a")`.
+    Anchor(String),
+}
+
+/// Splits raw HTML into tags and text, skipping `<!-- -->` comments.
+///
+/// pulldown-cmark hands over block HTML one line at a time, so a comment can
+/// start in one event and end several events later; the scanner remembers
+/// that between calls.
+#[derive(Debug, Default)]
+pub struct HtmlScanner {
+    in_comment: bool,
+}
+
+impl HtmlScanner {
+    pub fn scan<'a>(&mut self, html: &'a str) -> Vec<HtmlToken<'a>> {
+        let mut tokens = Vec::new();
+        let mut rest = html;
+
+        while !rest.is_empty() {
+            if self.in_comment {
+                match rest.find("-->") {
+                    Some(end) => {
+                        rest = &rest[end + 3..];
+                        self.in_comment = false;
+                    }
+                    None => break,
+                }
+                continue;
+            }
+
+            let Some(start) = rest.find('<') else {
+                tokens.push(HtmlToken::Text(rest));
+                break;
+            };
+            if start > 0 {
+                tokens.push(HtmlToken::Text(&rest[..start]));
+                rest = &rest[start..];
+            }
+
+            if let Some(comment) = rest.strip_prefix("<!--") {
+                self.in_comment = true;
+                rest = comment;
+                continue;
+            }
+            match parse_tag(rest) {
+                Some((token, len)) => {
+                    tokens.push(token);
+                    rest = &rest[len..];
+                }
+                None => {
+                    tokens.push(HtmlToken::Text(&rest[..1]));
+                    rest = &rest[1..];
+                }
+            }
+        }
+        tokens
+    }
+}
+
+/// Parse `<name ...>` or `</name>` at the start of `html`.
+fn parse_tag(html: &str) -> Option<(HtmlToken<'_>, usize)> {
+    let end = html.find('>')?;
+    let inner = &html[1..end];
+    let (closing, inner) = match inner.strip_prefix('/') {
+        Some(inner) => (true, inner),
+        None => (false, inner),
+    };
+    let name: String = inner
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    if name.is_empty() || !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let token = if closing {
+        HtmlToken::Close(name)
+    } else if let Some(href) = attribute(inner, "href").filter(|_| name == "a") {
+        HtmlToken::Anchor(decode_entities(href))
+    } else if inner.trim_end().ends_with('/') {
+        HtmlToken::SelfClosing(name)
+    } else {
+        HtmlToken::Open(name)
+    };
+    Some((token, end + 1))
+}
+
+/// Value of a quoted or bare `name=value` attribute in a tag's inner text.
+fn attribute<'a>(inner: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = inner;
+    while let Some(start) = rest.find(name) {
+        let before = rest[..start].chars().next_back();
+        let after = rest[start + name.len()..].trim_start();
+        rest = &rest[start + name.len()..];
+        if !before.is_some_and(char::is_whitespace) {
+            continue;
+        }
+        let Some(value) = after.strip_prefix('=').map(str::trim_start) else {
+            continue;
+        };
+        return match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value[1..].split(quote).next(),
+            _ => value.split(|c: char| c.is_whitespace() || c == '/').next(),
+        };
+    }
+    None
+}
+
+/// Decode the handful of entities that show up in hand-written HTML.
+pub fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    text.replace("&nbsp;", "\u{00A0}")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_tags_and_text() {
+        let mut scanner = HtmlScanner::default();
+        assert_eq!(
+            scanner.scan("<summary class=\"x\">More</SUMMARY><br/>"),
+            vec![
+                HtmlToken::Open("summary".to_string()),
+                HtmlToken::Text("More"),
+                HtmlToken::Close("summary".to_string()),
+                HtmlToken::SelfClosing("br".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scan_anchor_href() {
+        let mut scanner = HtmlScanner::default();
+        assert_eq!(
+            scanner.scan("<a class=\"x\" href=\"https://x.io/?a=1&amp;b=2\">x</a><a name=top>"),
+            vec![
+                HtmlToken::Anchor("https://x.io/?a=1&b=2".to_string()),
+                HtmlToken::Text("x"),
+                HtmlToken::Close("a".to_string()),
+                HtmlToken::Open("a".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_comment_spans_calls() {
+        let mut scanner = HtmlScanner::default();
+        assert_eq!(scanner.scan("a<!-- start\n"), vec![HtmlToken::Text("a")]);
+        assert_eq!(scanner.scan("still hidden\n"), vec![]);
+        assert_eq!(scanner.scan("end -->b"), vec![HtmlToken::Text("b")]);
+    }
+
+    #[test]
+    fn test_stray_angle_bracket_is_text() {
+        let mut scanner = HtmlScanner::default();
+        let tokens = scanner.scan("<3");
+        assert_eq!(tokens, vec![HtmlToken::Text("<"), HtmlToken::Text("3")]);
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(decode_entities("a &amp;lt; b &lt; c"), "a &lt; b < c");
+    }
+}
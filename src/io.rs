@@ -1,6 +1,7 @@
+use std::collections::HashMap;
 use std::fs;
 use std::io::{self, Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Read input from file or stdin.
 pub fn read_input(path: Option<&PathBuf>) -> io::Result<String> {
@@ -25,3 +26,51 @@ pub fn write_output(path: Option<&PathBuf>, content: &str) -> io::Result<()> {
         }
     }
 }
+
+/// Read a `key = value` mapping file.
+///
+/// Blank lines and lines starting with `#` are ignored. Keys and values are
+/// trimmed; a later line overrides an earlier one with the same key.
+pub fn read_mapping(path: &Path) -> io::Result<HashMap<String, String>> {
+    parse_mapping(&fs::read_to_string(path)?)
+}
+
+fn parse_mapping(contents: &str) -> io::Result<HashMap<String, String>> {
+    let mut mapping = HashMap::new();
+    for (number, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, value) = line
+            .split_once('=')
+            .filter(|(key, _)| !key.trim().is_empty())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: expected `key = value`", number + 1),
+                )
+            })?;
+        mapping.insert(key.trim().to_string(), value.trim().to_string());
+    }
+    Ok(mapping)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mapping() {
+        let mapping = parse_mapping("# comment\n\nship = 🚢\n:party: = 🥳\nship = ⛵\n").unwrap();
+        assert_eq!(mapping.len(), 2);
+        assert_eq!(mapping["ship"], "⛵");
+        assert_eq!(mapping[":party:"], "🥳");
+    }
+
+    #[test]
+    fn test_parse_mapping_reports_line() {
+        let err = parse_mapping("a = 1\nnot a mapping\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: expected `key = value`");
+    }
+}
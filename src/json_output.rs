@@ -1,11 +1,37 @@
-pub fn format_json(text: &str, char_count: usize, limit: usize) -> String {
-    let escaped = json_escape(text);
+/// A piece of text to post, with its length against LinkedIn's limit for it.
+#[derive(Debug, Clone, Copy)]
+pub struct Part<'a> {
+    pub text: &'a str,
+    pub char_count: usize,
+    pub limit: usize,
+}
+
+impl Part<'_> {
+    fn fields(&self) -> String {
+        format!(
+            "\"text\":\"{}\",\"char_count\":{},\"limit\":{},\"limit_exceeded\":{}",
+            json_escape(self.text),
+            self.char_count,
+            self.limit,
+            self.char_count > self.limit
+        )
+    }
+}
+
+pub fn format_json(post: &Part, preview_url: Option<&str>, first_comment: Option<&Part>) -> String {
+    let preview_url = match preview_url {
+        Some(url) => format!("\"{}\"", json_escape(url)),
+        None => "null".to_string(),
+    };
+    let first_comment = match first_comment {
+        Some(comment) => format!("{{{}}}", comment.fields()),
+        None => "null".to_string(),
+    };
     format!(
-        "{{\"text\":\"{}\",\"char_count\":{},\"limit\":{},\"limit_exceeded\":{}}}",
-        escaped,
-        char_count,
-        limit,
-        char_count > limit
+        "{{{},\"preview_url\":{},\"first_comment\":{}}}",
+        post.fields(),
+        preview_url,
+        first_comment
     )
 }
 
@@ -35,11 +61,33 @@ mod tests {
         let expected = std::fs::read_to_string("tests/fixtures/json.txt").unwrap();
         let options = ConvertOptions::default();
         let converted = convert(&input, &options);
-        let char_count = converted.chars().count();
-        let output = format_json(&converted, char_count, 3000);
+        let post = Part {
+            text: &converted,
+            char_count: converted.chars().count(),
+            limit: 3000,
+        };
+        let output = format_json(&post, None, None);
         assert_eq!(output, expected.trim_end_matches('\n'));
     }
 
+    #[test]
+    fn test_json_preview_url_and_first_comment() {
+        let post = Part {
+            text: "Hi\n\nhttps://x.io",
+            char_count: 16,
+            limit: 3000,
+        };
+        let comment = Part {
+            text: "Links",
+            char_count: 5,
+            limit: 4,
+        };
+        let output = format_json(&post, Some("https://x.io"), Some(&comment));
+        assert!(output.ends_with(
+            ",\"preview_url\":\"https://x.io\",\"first_comment\":{\"text\":\"Links\",\"char_count\":5,\"limit\":4,\"limit_exceeded\":true}}"
+        ));
+    }
+
     #[test]
     fn test_json_escape_quotes() {
         let escaped = json_escape("a\"b");
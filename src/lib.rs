@@ -1,7 +1,22 @@
+pub mod callout;
 pub mod carbon;
 pub mod cli;
 pub mod code_image;
 pub mod converter;
+pub mod emoji;
+pub mod entities;
+pub mod first_comment;
+pub mod footnotes;
+pub mod front_matter;
+pub mod heading;
+pub mod html;
 pub mod io;
 pub mod json_output;
+pub mod links;
+pub mod math;
+pub mod punctuation;
+pub mod spans;
+pub mod table;
 pub mod unicode;
+pub mod urls;
+pub mod wikilink;
@@ -0,0 +1,111 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkStyle {
+    /// text (https://example.com/page)
+    Inline,
+    /// text [1], with `[1] https://example.com/page` listed at the end
+    References,
+    /// text (example.com)
+    Domain,
+    /// text
+    Text,
+    /// https://example.com/page
+    Url,
+}
+
+/// URLs of reference-style links, numbered in order of first use.
+#[derive(Debug, Default)]
+pub struct LinkReferences {
+    urls: Vec<String>,
+}
+
+impl LinkReferences {
+    /// Number for a URL; the same URL always gets the same number.
+    pub fn number(&mut self, url: &str) -> usize {
+        if let Some(index) = self.urls.iter().position(|known| known == url) {
+            return index + 1;
+        }
+        self.urls.push(url.to_string());
+        self.urls.len()
+    }
+
+    /// `[n] url` lines, or `None` when no link was numbered.
+    pub fn render(&self) -> Option<String> {
+        if self.urls.is_empty() {
+            return None;
+        }
+        let lines: Vec<String> = self
+            .urls
+            .iter()
+            .enumerate()
+            .map(|(i, url)| format!("[{}] {}", i + 1, url))
+            .collect();
+        Some(lines.join("\n"))
+    }
+}
+
+/// The URL as shown to readers: `mailto:` and `tel:` drop their scheme.
+///
+/// # Example
+/// ```
+/// use mdtolinkedin::links::display_url;
+/// assert_eq!(display_url("mailto:hi@example.com"), "hi@example.com");
+/// assert_eq!(display_url("https://example.com"), "https://example.com");
+/// ```
+pub fn display_url(url: &str) -> &str {
+    url.strip_prefix("mailto:")
+        .or_else(|| url.strip_prefix("tel:"))
+        .unwrap_or(url)
+}
+
+/// Whether a URL points to another site, as opposed to `mailto:` or a relative path.
+pub fn is_external(url: &str) -> bool {
+    let scheme = url.split_once("://").map(|(scheme, _)| scheme);
+    scheme.is_some_and(|scheme| {
+        scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https")
+    })
+}
+
+/// Host of a URL without `www.`; URLs without a host are returned as shown.
+///
+/// # Example
+/// ```
+/// use mdtolinkedin::links::domain;
+/// assert_eq!(domain("https://www.example.com/a?b=c"), "example.com");
+/// assert_eq!(domain("/relative/path"), "/relative/path");
+/// ```
+pub fn domain(url: &str) -> &str {
+    let Some((_, rest)) = url.split_once("://") else {
+        return display_url(url);
+    };
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or(rest);
+    let host = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    host.strip_prefix("www.").unwrap_or(host)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_numbers_shared() {
+        let mut references = LinkReferences::default();
+        assert_eq!(references.number("https://a.test"), 1);
+        assert_eq!(references.number("https://b.test"), 2);
+        assert_eq!(references.number("https://a.test"), 1);
+        assert_eq!(
+            references.render().as_deref(),
+            Some("[1] https://a.test\n[2] https://b.test")
+        );
+    }
+
+    #[test]
+    fn test_domain() {
+        assert_eq!(
+            domain("http://user@docs.example.com:8080/x"),
+            "docs.example.com:8080"
+        );
+        assert_eq!(domain("mailto:hi@example.com"), "hi@example.com");
+    }
+}
@@ -1,8 +1,22 @@
 use clap::Parser;
-use mdtolinkedin::cli::{Cli, CodeBlocksArg, OutputFormat};
+use mdtolinkedin::callout::default_callout_icons;
+use mdtolinkedin::cli::{
+    Cli, CodeBlocksArg, DefinitionsArg, FootnoteHeadingArg, InlineCodeArg, LinksArg, LocaleArg,
+    OutputFormat, RuleArg, TablePaddingArg, TablesArg, TaskStyleArg, UnstyledFallbackArg,
+};
 use mdtolinkedin::code_image::CodeImageOptions;
-use mdtolinkedin::converter::{self, CodeBlockMode, ConvertOptions};
-use mdtolinkedin::{io, json_output};
+use mdtolinkedin::converter::{
+    self, CodeBlockMode, ConvertOptions, DefinitionLayout, InlineCodeStyle, RuleStyle,
+    TaskListStyle,
+};
+use mdtolinkedin::footnotes::FootnoteHeadingStyle;
+use mdtolinkedin::heading::default_heading_styles;
+use mdtolinkedin::links::LinkStyle;
+use mdtolinkedin::punctuation::QuoteStyle;
+use mdtolinkedin::table::{TableMode, TablePadding};
+use mdtolinkedin::unicode::UnstyledFallback;
+use mdtolinkedin::urls::UrlRewrite;
+use mdtolinkedin::{first_comment, io, json_output};
 
 fn main() {
     let args = Cli::parse();
@@ -43,14 +57,118 @@ fn main() {
         None
     };
 
+    let mut heading_styles = default_heading_styles();
+    heading_styles.extend(args.heading_style.iter().cloned());
+
+    let emoji_map = match args.emoji_map.as_deref().map(io::read_mapping) {
+        Some(Ok(mapping)) => mapping,
+        Some(Err(e)) => {
+            eprintln!("Error reading emoji map: {}", e);
+            std::process::exit(1);
+        }
+        None => Default::default(),
+    };
+
+    let wikilink_urls = match args.wikilink_map.as_deref().map(io::read_mapping) {
+        Some(Ok(mapping)) => mapping,
+        Some(Err(e)) => {
+            eprintln!("Error reading wikilink map: {}", e);
+            std::process::exit(1);
+        }
+        None => Default::default(),
+    };
+
+    let mut callout_icons = default_callout_icons();
+    callout_icons.extend(args.callout_icon.iter().cloned());
+
     let options = ConvertOptions {
         code_block_mode,
         bullet: args.bullet.unwrap_or_else(|| "•".to_string()),
         trim_output: !args.no_trim,
         plain: args.plain,
         code_image: code_image_options,
+        table_mode: match args.tables {
+            TablesArg::Grid => TableMode::Grid,
+            TablesArg::KeyValue => TableMode::KeyValue,
+            TablesArg::Bullets => TableMode::Bullets,
+        },
+        table_padding: match args.table_padding {
+            TablePaddingArg::Monospace => TablePadding::Monospace,
+            TablePaddingArg::Figure => TablePadding::Figure,
+        },
+        task_list_style: match args.task_style {
+            TaskStyleArg::Ballot => TaskListStyle::Ballot,
+            TaskStyleArg::Emoji => TaskListStyle::Emoji,
+            TaskStyleArg::Check => TaskListStyle::Check,
+        },
+        footnote_heading: args.footnote_heading.clone(),
+        footnote_heading_style: match args.footnote_heading_style {
+            FootnoteHeadingArg::Bold => FootnoteHeadingStyle::Bold,
+            FootnoteHeadingArg::Italic => FootnoteHeadingStyle::Italic,
+            FootnoteHeadingArg::Plain => FootnoteHeadingStyle::Plain,
+        },
+        rule: match (args.rule_text.clone(), args.rule) {
+            (Some(text), _) => RuleStyle::Custom(text),
+            (None, RuleArg::Line) => RuleStyle::Line,
+            (None, RuleArg::Dots) => RuleStyle::Dots,
+            (None, RuleArg::Wave) => RuleStyle::Wave,
+            (None, RuleArg::Blank) => RuleStyle::Blank,
+        },
+        heading_styles,
+        inline_code: match args.inline_code {
+            InlineCodeArg::Monospace => InlineCodeStyle::Monospace,
+            InlineCodeArg::Backticks => InlineCodeStyle::Backticks,
+            InlineCodeArg::Guillemets => InlineCodeStyle::Guillemets,
+        },
+        style_digits: !args.no_styled_digits,
+        unstyled_fallback: match args.unstyled_fallback {
+            UnstyledFallbackArg::Keep => UnstyledFallback::Keep,
+            UnstyledFallbackArg::Underline => UnstyledFallback::Underline,
+            UnstyledFallbackArg::Brackets => UnstyledFallback::Brackets,
+        },
+        inline_spans: args.inline_spans,
+        highlight_family: args.highlight_style,
+        emoji_shortcodes: !args.no_emoji,
+        emoji_map,
+        callout_icons,
+        definition_layout: match args.definitions {
+            DefinitionsArg::Dash => DefinitionLayout::Dash,
+            DefinitionsArg::Indent => DefinitionLayout::Indent,
+        },
+        definition_term_family: args.definition_style,
+        wikilink_template: args.wikilink_url.clone(),
+        wikilink_urls,
+        smart_punctuation: !args.no_smart_punctuation,
+        subscript: args.subscript,
+        math: args.math,
+        quote_style: match args.locale {
+            LocaleArg::En => QuoteStyle::English,
+            LocaleArg::Fr => QuoteStyle::French,
+            LocaleArg::De => QuoteStyle::German,
+            LocaleArg::Ja => QuoteStyle::Japanese,
+        },
+        link_style: match args.links {
+            LinksArg::Inline => LinkStyle::Inline,
+            LinksArg::References => LinkStyle::References,
+            LinksArg::Domain => LinkStyle::Domain,
+            LinksArg::Text => LinkStyle::Text,
+            LinksArg::Url => LinkStyle::Url,
+        },
+        url_rewrite: UrlRewrite {
+            clean: !args.no_clean_urls,
+            utm: args.utm.clone(),
+            allow: args.url_allow.clone(),
+            deny: args.url_deny.clone(),
+        },
+        links_in_comment: args.links_in_comment,
     };
-    let output = converter::convert(&input, &options);
+    let conversion = converter::convert_document(&input, &options);
+    if !args.no_warn {
+        for warning in &conversion.warnings {
+            eprintln!("⚠️  Warning: {}", warning);
+        }
+    }
+    let output = conversion.text;
 
     // Character count warning
     let char_count = output.chars().count();
@@ -62,9 +180,46 @@ fn main() {
         );
     }
 
+    let comment = conversion
+        .first_comment
+        .as_deref()
+        .map(|text| json_output::Part {
+            text,
+            char_count: text.chars().count(),
+            limit: args
+                .comment_max_chars
+                .unwrap_or(first_comment::COMMENT_LIMIT),
+        });
+    if let Some(comment) =
+        comment.filter(|comment| !args.no_warn && comment.char_count > comment.limit)
+    {
+        eprintln!(
+            "⚠️  Warning: First comment is {} characters (limit: {})",
+            comment.char_count, comment.limit
+        );
+    }
+
     let formatted_output = match args.format {
-        OutputFormat::Text => output,
-        OutputFormat::Json => json_output::format_json(&output, char_count, warn_limit),
+        OutputFormat::Text => match &comment {
+            Some(comment) if output.is_empty() => {
+                format!("{}\n\n{}", first_comment::DIVIDER, comment.text)
+            }
+            Some(comment) => format!(
+                "{}\n\n{}\n\n{}",
+                output,
+                first_comment::DIVIDER,
+                comment.text
+            ),
+            None => output,
+        },
+        OutputFormat::Json => {
+            let post = json_output::Part {
+                text: &output,
+                char_count,
+                limit: warn_limit,
+            };
+            json_output::format_json(&post, conversion.preview_url.as_deref(), comment.as_ref())
+        }
     };
 
     let final_output = if args.format == OutputFormat::Text && args.no_trim {
@@ -0,0 +1,434 @@
+use crate::unicode::{to_subscript, to_superscript, StyleFamily};
+
+/// Result of converting a LaTeX math span.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MathText {
+    pub text: String,
+    /// Commands that had no Unicode equivalent and were written out by name.
+    pub unsupported: Vec<String>,
+}
+
+/// Convert a practical subset of LaTeX math to plain Unicode text.
+///
+/// Greek letters, common operators and arrows, `^`/`_`, `\frac`, `\sqrt`,
+/// accents and the `\mathbb`-style alphabets are supported. Anything else
+/// degrades to its command name without the backslash and is listed in
+/// [`MathText::unsupported`].
+///
+/// # Example
+/// ```
+/// use mdtolinkedin::math::to_unicode;
+/// assert_eq!(to_unicode(r"\sum_{i=1}^n x_i").text, "∑ᵢ₌₁ⁿ xᵢ");
+/// ```
+pub fn to_unicode(latex: &str) -> MathText {
+    let mut parser = MathParser {
+        chars: latex.chars().collect(),
+        pos: 0,
+        unsupported: Vec::new(),
+    };
+    let text = parser.sequence(false);
+    let text = text
+        .lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    MathText {
+        text,
+        unsupported: parser.unsupported,
+    }
+}
+
+struct MathParser {
+    chars: Vec<char>,
+    pos: usize,
+    unsupported: Vec<String>,
+}
+
+impl MathParser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        Some(c)
+    }
+
+    /// Everything up to the end of input, or up to the closing `}` of a group.
+    fn sequence(&mut self, in_group: bool) -> String {
+        let mut out = String::new();
+        while let Some(c) = self.next() {
+            match c {
+                '}' if in_group => break,
+                '}' => {}
+                '{' => out.push_str(&self.sequence(true)),
+                '^' => out.push_str(&to_superscript(&self.argument())),
+                '_' => out.push_str(&to_subscript(&self.argument())),
+                '\\' => out.push_str(&self.command()),
+                '&' => {}
+                '~' => out.push(' '),
+                '\'' => out.push('′'),
+                _ => out.push(c),
+            }
+        }
+        out
+    }
+
+    /// A single argument: a `{group}`, a command, or one character.
+    fn argument(&mut self) -> String {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+        match self.next() {
+            Some('{') => self.sequence(true),
+            Some('\\') => self.command(),
+            Some(c) => c.to_string(),
+            None => String::new(),
+        }
+    }
+
+    /// `[n]` after `\sqrt`, if present.
+    fn optional_argument(&mut self) -> Option<String> {
+        if self.peek() != Some('[') {
+            return None;
+        }
+        let end = self.chars[self.pos..].iter().position(|&c| c == ']')?;
+        let arg: String = self.chars[self.pos + 1..self.pos + end].iter().collect();
+        self.pos += end + 1;
+        Some(arg)
+    }
+
+    fn command(&mut self) -> String {
+        let mut name = String::new();
+        while let Some(c) = self.peek().filter(char::is_ascii_alphabetic) {
+            name.push(c);
+            self.pos += 1;
+        }
+        if name.is_empty() {
+            return match self.next() {
+                Some('\\') => "\n".to_string(),
+                Some(',' | ';' | ':' | ' ') => " ".to_string(),
+                Some('!') | None => String::new(),
+                Some(c) => c.to_string(),
+            };
+        }
+
+        if let Some(symbol) = symbol(&name) {
+            return symbol.to_string();
+        }
+        if let Some(family) = alphabet(&name) {
+            return family.apply(&self.argument(), true);
+        }
+        if let Some(mark) = accent(&name) {
+            let arg = self.argument();
+            let mut out = String::with_capacity(arg.len() * 3);
+            for c in arg.chars() {
+                out.push(c);
+                out.push(mark);
+            }
+            return out;
+        }
+        match name.as_str() {
+            "quad" | "qquad" => " ".to_string(),
+            "text" | "textrm" | "textnormal" | "mathrm" | "operatorname" | "mbox" => {
+                self.argument()
+            }
+            "sin" | "cos" | "tan" | "cot" | "sec" | "csc" | "arcsin" | "arccos" | "arctan"
+            | "sinh" | "cosh" | "tanh" | "log" | "ln" | "lg" | "exp" | "lim" | "max" | "min"
+            | "sup" | "inf" | "det" | "gcd" | "deg" | "dim" | "ker" | "arg" | "Pr" => name,
+            "frac" | "dfrac" | "tfrac" => {
+                let numerator = self.argument();
+                let denominator = self.argument();
+                fraction(&numerator, &denominator)
+            }
+            "sqrt" => {
+                let index = self.optional_argument();
+                let radicand = self.argument();
+                let root = match index.as_deref().map(str::trim) {
+                    None | Some("2") => "√".to_string(),
+                    Some("3") => "∛".to_string(),
+                    Some("4") => "∜".to_string(),
+                    Some(n) => format!("{}√", to_superscript(n)),
+                };
+                format!("{}{}", root, group(&radicand))
+            }
+            "left" | "right" | "bigl" | "bigr" | "Bigl" | "Bigr" | "big" | "Big" => {
+                if self.peek() == Some('.') {
+                    self.pos += 1;
+                }
+                String::new()
+            }
+            "displaystyle" | "textstyle" | "limits" | "nolimits" => String::new(),
+            "begin" | "end" => {
+                self.argument();
+                String::new()
+            }
+            _ => {
+                let command = format!("\\{}", name);
+                if !self.unsupported.contains(&command) {
+                    self.unsupported.push(command);
+                }
+                name
+            }
+        }
+    }
+}
+
+/// Wrap anything longer than one symbol in parentheses.
+fn group(text: &str) -> String {
+    if text.chars().count() <= 1 || text.chars().all(char::is_alphanumeric) {
+        text.to_string()
+    } else {
+        format!("({})", text)
+    }
+}
+
+fn fraction(numerator: &str, denominator: &str) -> String {
+    let vulgar = match (numerator.trim(), denominator.trim()) {
+        ("1", "2") => Some('½'),
+        ("1", "3") => Some('⅓'),
+        ("2", "3") => Some('⅔'),
+        ("1", "4") => Some('¼'),
+        ("3", "4") => Some('¾'),
+        ("1", "5") => Some('⅕'),
+        ("2", "5") => Some('⅖'),
+        ("3", "5") => Some('⅗'),
+        ("4", "5") => Some('⅘'),
+        ("1", "6") => Some('⅙'),
+        ("5", "6") => Some('⅚'),
+        ("1", "7") => Some('⅐'),
+        ("1", "8") => Some('⅛'),
+        ("3", "8") => Some('⅜'),
+        ("5", "8") => Some('⅝'),
+        ("7", "8") => Some('⅞'),
+        ("1", "9") => Some('⅑'),
+        ("1", "10") => Some('⅒'),
+        _ => None,
+    };
+    match vulgar {
+        Some(c) => c.to_string(),
+        None => format!("{}/{}", group(numerator.trim()), group(denominator.trim())),
+    }
+}
+
+fn alphabet(name: &str) -> Option<StyleFamily> {
+    let family = match name {
+        "mathbf" | "textbf" | "boldsymbol" | "bm" => StyleFamily::Bold,
+        "mathit" | "textit" => StyleFamily::Italic,
+        "mathbb" => StyleFamily::DoubleStruck,
+        "mathcal" | "mathscr" => StyleFamily::Script,
+        "mathfrak" => StyleFamily::Fraktur,
+        "mathsf" | "textsf" => StyleFamily::SansSerif,
+        "mathtt" | "texttt" => StyleFamily::Monospace,
+        _ => return None,
+    };
+    Some(family)
+}
+
+fn accent(name: &str) -> Option<char> {
+    let mark = match name {
+        "bar" | "overline" => '\u{0305}',
+        "hat" | "widehat" => '\u{0302}',
+        "tilde" | "widetilde" => '\u{0303}',
+        "dot" => '\u{0307}',
+        "ddot" => '\u{0308}',
+        "vec" => '\u{20D7}',
+        "underline" => '\u{0332}',
+        _ => return None,
+    };
+    Some(mark)
+}
+
+fn symbol(name: &str) -> Option<&'static str> {
+    let symbol = match name {
+        // Greek
+        "alpha" => "α",
+        "beta" => "β",
+        "gamma" => "γ",
+        "delta" => "δ",
+        "epsilon" => "ϵ",
+        "varepsilon" => "ε",
+        "zeta" => "ζ",
+        "eta" => "η",
+        "theta" => "θ",
+        "vartheta" => "ϑ",
+        "iota" => "ι",
+        "kappa" => "κ",
+        "lambda" => "λ",
+        "mu" => "μ",
+        "nu" => "ν",
+        "xi" => "ξ",
+        "omicron" => "ο",
+        "pi" => "π",
+        "varpi" => "ϖ",
+        "rho" => "ρ",
+        "varrho" => "ϱ",
+        "sigma" => "σ",
+        "varsigma" => "ς",
+        "tau" => "τ",
+        "upsilon" => "υ",
+        "phi" => "ϕ",
+        "varphi" => "φ",
+        "chi" => "χ",
+        "psi" => "ψ",
+        "omega" => "ω",
+        "Gamma" => "Γ",
+        "Delta" => "Δ",
+        "Theta" => "Θ",
+        "Lambda" => "Λ",
+        "Xi" => "Ξ",
+        "Pi" => "Π",
+        "Sigma" => "Σ",
+        "Upsilon" => "Υ",
+        "Phi" => "Φ",
+        "Psi" => "Ψ",
+        "Omega" => "Ω",
+        // Big operators
+        "sum" => "∑",
+        "prod" => "∏",
+        "coprod" => "∐",
+        "int" => "∫",
+        "iint" => "∬",
+        "iiint" => "∭",
+        "oint" => "∮",
+        "bigcup" => "⋃",
+        "bigcap" => "⋂",
+        // Relations
+        "leq" | "le" => "≤",
+        "geq" | "ge" => "≥",
+        "neq" | "ne" => "≠",
+        "approx" => "≈",
+        "equiv" => "≡",
+        "sim" => "∼",
+        "simeq" => "≃",
+        "cong" => "≅",
+        "propto" => "∝",
+        "ll" => "≪",
+        "gg" => "≫",
+        "in" => "∈",
+        "notin" => "∉",
+        "ni" => "∋",
+        "subset" => "⊂",
+        "subseteq" => "⊆",
+        "supset" => "⊃",
+        "supseteq" => "⊇",
+        "mid" => "∣",
+        "parallel" => "∥",
+        "perp" => "⊥",
+        // Arrows
+        "to" | "rightarrow" => "→",
+        "leftarrow" | "gets" => "←",
+        "leftrightarrow" => "↔",
+        "Rightarrow" | "implies" => "⇒",
+        "Leftarrow" => "⇐",
+        "Leftrightarrow" | "iff" => "⇔",
+        "mapsto" => "↦",
+        "uparrow" => "↑",
+        "downarrow" => "↓",
+        // Binary operators
+        "pm" => "±",
+        "mp" => "∓",
+        "times" => "×",
+        "div" => "÷",
+        "cdot" => "⋅",
+        "ast" => "∗",
+        "circ" => "∘",
+        "bullet" => "∙",
+        "oplus" => "⊕",
+        "otimes" => "⊗",
+        "cup" => "∪",
+        "cap" => "∩",
+        "setminus" => "∖",
+        "land" | "wedge" => "∧",
+        "lor" | "vee" => "∨",
+        "neg" | "lnot" => "¬",
+        // Misc
+        "infty" => "∞",
+        "partial" => "∂",
+        "nabla" => "∇",
+        "forall" => "∀",
+        "exists" => "∃",
+        "nexists" => "∄",
+        "emptyset" | "varnothing" => "∅",
+        "hbar" => "ℏ",
+        "ell" => "ℓ",
+        "Re" => "ℜ",
+        "Im" => "ℑ",
+        "aleph" => "ℵ",
+        "angle" => "∠",
+        "degree" => "°",
+        "prime" => "′",
+        "therefore" => "∴",
+        "because" => "∵",
+        "ldots" | "dots" => "…",
+        "cdots" => "⋯",
+        "vdots" => "⋮",
+        "ddots" => "⋱",
+        "langle" => "⟨",
+        "rangle" => "⟩",
+        "lceil" => "⌈",
+        "rceil" => "⌉",
+        "lfloor" => "⌊",
+        "rfloor" => "⌋",
+        "lbrace" => "{",
+        "rbrace" => "}",
+        "vert" => "|",
+        "Vert" => "‖",
+        _ => return None,
+    };
+    Some(symbol)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_superscript_and_operators() {
+        assert_eq!(to_unicode("E = mc^2").text, "E = mc²");
+        assert_eq!(
+            to_unicode(r"a \leq b \neq c \to \infty").text,
+            "a ≤ b ≠ c → ∞"
+        );
+    }
+
+    #[test]
+    fn test_greek_and_alphabets() {
+        assert_eq!(
+            to_unicode(r"\alpha + \Omega \in \mathbb{R}").text,
+            "α + Ω ∈ ℝ"
+        );
+        assert_eq!(to_unicode(r"\mathcal{L}").text, "ℒ");
+    }
+
+    #[test]
+    fn test_fractions_and_roots() {
+        assert_eq!(to_unicode(r"\frac{1}{2}").text, "½");
+        assert_eq!(to_unicode(r"\frac{a+b}{n}").text, "(a+b)/n");
+        assert_eq!(to_unicode(r"\sqrt{x^2 + 1}").text, "√(x² + 1)");
+        assert_eq!(to_unicode(r"\sqrt[3]{8}").text, "∛8");
+    }
+
+    #[test]
+    fn test_script_fallback() {
+        assert_eq!(to_unicode("x_{max}").text, "xₘₐₓ");
+        assert_eq!(to_unicode("e^{Q}").text, "e^(Q)");
+    }
+
+    #[test]
+    fn test_unsupported_commands_degrade() {
+        let math = to_unicode(r"\foo{x} + \bar{y} + \foo");
+        assert_eq!(math.text, "foox + y\u{0305} + foo");
+        assert_eq!(math.unsupported, vec![r"\foo".to_string()]);
+    }
+
+    #[test]
+    fn test_line_breaks() {
+        assert_eq!(
+            to_unicode(r"\begin{aligned} a &= 1 \\ b &= 2 \end{aligned}").text,
+            "a = 1\nb = 2"
+        );
+    }
+}
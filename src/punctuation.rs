@@ -0,0 +1,121 @@
+use std::borrow::Cow;
+
+/// Quotation marks used after smart punctuation has curled the quotes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// “double” and ‘single’
+    English,
+    /// «double» and ‹single›
+    French,
+    /// „double“ and ‚single‘
+    German,
+    /// 「double」 and 『single』
+    Japanese,
+}
+
+impl QuoteStyle {
+    /// Opening and closing double quotes, then opening and closing single quotes.
+    fn marks(self) -> [char; 4] {
+        match self {
+            QuoteStyle::English => ['“', '”', '‘', '’'],
+            QuoteStyle::French => ['«', '»', '‹', '›'],
+            QuoteStyle::German => ['„', '“', '‚', '‘'],
+            QuoteStyle::Japanese => ['「', '」', '『', '』'],
+        }
+    }
+}
+
+/// Swap the English curly quotes produced by pulldown-cmark for `style`.
+///
+/// `’` doubles as the apostrophe, so it only becomes a closing quote while a
+/// single quote is open and no letter follows; `single_open` carries that
+/// across text chunks of the same paragraph.
+///
+/// # Example
+/// ```
+/// use mdtolinkedin::punctuation::{localize_quotes, QuoteStyle};
+/// let mut open = false;
+/// let text = localize_quotes("“Don’t” she said", QuoteStyle::German, &mut open);
+/// assert_eq!(text, "„Don’t“ she said");
+/// ```
+pub fn localize_quotes<'a>(
+    text: &'a str,
+    style: QuoteStyle,
+    single_open: &mut bool,
+) -> Cow<'a, str> {
+    if style == QuoteStyle::English || !text.contains(['“', '”', '‘', '’']) {
+        return Cow::Borrowed(text);
+    }
+    let [open_double, close_double, open_single, close_single] = style.marks();
+    let mut localized = String::with_capacity(text.len() + 8);
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let mapped = match c {
+            '“' => open_double,
+            '”' => close_double,
+            '‘' => {
+                *single_open = true;
+                open_single
+            }
+            // A ’ followed by a letter is an apostrophe: “the team’s”
+            '’' if *single_open && !chars.peek().is_some_and(|next| next.is_alphabetic()) => {
+                *single_open = false;
+                close_single
+            }
+            _ => c,
+        };
+        localized.push(mapped);
+    }
+    Cow::Owned(localized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_english_is_unchanged() {
+        let mut open = false;
+        assert!(matches!(
+            localize_quotes("“hi”", QuoteStyle::English, &mut open),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn test_french_and_japanese() {
+        let mut open = false;
+        assert_eq!(
+            localize_quotes("“Bonjour” et ‘salut’", QuoteStyle::French, &mut open),
+            "«Bonjour» et ‹salut›"
+        );
+        assert_eq!(
+            localize_quotes("“hai”", QuoteStyle::Japanese, &mut open),
+            "「hai」"
+        );
+    }
+
+    #[test]
+    fn test_apostrophe_kept_outside_single_quotes() {
+        let mut open = false;
+        assert_eq!(
+            localize_quotes("it’s ‘the team’s call’ now", QuoteStyle::French, &mut open),
+            "it’s ‹the team’s call› now"
+        );
+        assert!(!open);
+    }
+
+    #[test]
+    fn test_single_quote_spans_chunks() {
+        let mut open = false;
+        assert_eq!(
+            localize_quotes("‘open ", QuoteStyle::German, &mut open),
+            "‚open "
+        );
+        assert!(open);
+        assert_eq!(
+            localize_quotes("close’", QuoteStyle::German, &mut open),
+            "close‘"
+        );
+    }
+}
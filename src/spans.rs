@@ -0,0 +1,221 @@
+use crate::unicode::StyleFamily;
+
+/// A piece of text split by the inline span syntax.
+///
+/// `{family}[text]` styles `text` in any `StyleFamily` by name, and
+/// `==text==` styles it in the configured highlight family. Whether a `]` or
+/// `==` actually closes a span depends on what is open, so the converter
+/// decides and falls back to the literal text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanToken<'a> {
+    Text(&'a str),
+    Open(StyleFamily),
+    Close,
+    Highlight,
+}
+
+impl SpanToken<'_> {
+    /// The source text for a marker that turned out not to open or close a span.
+    pub fn literal(&self) -> &str {
+        match self {
+            SpanToken::Text(text) => text,
+            SpanToken::Open(_) => "",
+            SpanToken::Close => "]",
+            SpanToken::Highlight => "==",
+        }
+    }
+}
+
+pub fn tokenize(text: &str) -> Vec<SpanToken<'_>> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut i = 0;
+    let bytes = text.as_bytes();
+
+    while i < bytes.len() {
+        let marker = match bytes[i] {
+            b'{' => open_marker(&text[i..]),
+            b']' => Some((SpanToken::Close, 1)),
+            b'=' if bytes.get(i + 1) == Some(&b'=') && is_flanking(text, i, i + 2) => {
+                Some((SpanToken::Highlight, 2))
+            }
+            _ => None,
+        };
+        match marker {
+            Some((token, len)) => {
+                if start < i {
+                    tokens.push(SpanToken::Text(&text[start..i]));
+                }
+                tokens.push(token);
+                i += len;
+                start = i;
+            }
+            None => i += 1,
+        }
+    }
+    if start < text.len() {
+        tokens.push(SpanToken::Text(&text[start..]));
+    }
+    tokens
+}
+
+/// `==` only counts as a marker when it touches a word on at least one side,
+/// so comparisons like `a == b` stay as written. The ends of a text chunk
+/// count as touching, since they sit next to other inline markup.
+fn is_flanking(text: &str, start: usize, end: usize) -> bool {
+    let before = text[..start].chars().next_back();
+    let after = text[end..].chars().next();
+    [before, after]
+        .into_iter()
+        .any(|c| c.is_none_or(|c| !c.is_whitespace()))
+}
+
+/// Parse `{family}[` at the start of `text`.
+fn open_marker(text: &str) -> Option<(SpanToken<'_>, usize)> {
+    let end = text.find('}')?;
+    if !text[end + 1..].starts_with('[') {
+        return None;
+    }
+    let family = StyleFamily::from_name(&text[1..end])?;
+    Some((SpanToken::Open(family), end + 2))
+}
+
+/// A piece of text split on Pandoc-style `^sup^` and `~sub~` runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptRun<'a> {
+    Text(&'a str),
+    Superscript(&'a str),
+    Subscript(&'a str),
+}
+
+/// Find `^x^` and, with `subscript`, `~x~` inside words, e.g. `x^2^` or `H~2~O`.
+///
+/// pulldown-cmark only parses these at word boundaries, so the rest are
+/// picked up here. As in Pandoc, the content can't be empty or contain
+/// whitespace, and `~~` is left to strikethrough. Content without letters or
+/// digits, like the `^-^` emoticon, is left as written.
+pub fn script_runs(text: &str, subscript: bool) -> Vec<ScriptRun<'_>> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    let mut i = 0;
+    let bytes = text.as_bytes();
+
+    while i < bytes.len() {
+        let marker = bytes[i];
+        if marker != b'^' && !(subscript && marker == b'~') {
+            i += 1;
+            continue;
+        }
+        if bytes.get(i + 1) == Some(&marker) {
+            while bytes.get(i) == Some(&marker) {
+                i += 1;
+            }
+            continue;
+        }
+        let body = &text[i + 1..];
+        let end = body
+            .find(|c: char| c == marker as char || c.is_whitespace())
+            .filter(|&end| end > 0 && body[end..].starts_with(marker as char))
+            .filter(|&end| is_script_content(&body[..end]));
+        let Some(end) = end else {
+            i += 1;
+            continue;
+        };
+        if start < i {
+            runs.push(ScriptRun::Text(&text[start..i]));
+        }
+        let content = &body[..end];
+        runs.push(if marker == b'^' {
+            ScriptRun::Superscript(content)
+        } else {
+            ScriptRun::Subscript(content)
+        });
+        i += end + 2;
+        start = i;
+    }
+    if start < text.len() {
+        runs.push(ScriptRun::Text(&text[start..]));
+    }
+    runs
+}
+
+/// Whether `^content^` or `~content~` is worth raising or lowering: a lone
+/// sign or other punctuation is more likely an emoticon such as `^-^`.
+pub fn is_script_content(content: &str) -> bool {
+    content.chars().any(char::is_alphanumeric)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_bracket_span() {
+        assert_eq!(
+            tokenize("a {script}[b] c"),
+            vec![
+                SpanToken::Text("a "),
+                SpanToken::Open(StyleFamily::Script),
+                SpanToken::Text("b"),
+                SpanToken::Close,
+                SpanToken::Text(" c"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_highlight() {
+        assert_eq!(
+            tokenize("==hi=="),
+            vec![
+                SpanToken::Highlight,
+                SpanToken::Text("hi"),
+                SpanToken::Highlight
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_family_is_text() {
+        assert_eq!(tokenize("{nope}[x"), vec![SpanToken::Text("{nope}[x")]);
+    }
+
+    #[test]
+    fn test_spaced_equals_is_text() {
+        assert_eq!(tokenize("a == b"), vec![SpanToken::Text("a == b")]);
+    }
+
+    #[test]
+    fn test_script_runs_inside_words() {
+        assert_eq!(
+            script_runs("H~2~O is x^2^", true),
+            vec![
+                ScriptRun::Text("H"),
+                ScriptRun::Subscript("2"),
+                ScriptRun::Text("O is x"),
+                ScriptRun::Superscript("2"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_script_runs_need_tight_content() {
+        assert_eq!(
+            script_runs("a ^ b ^ c, x^^ and ~~y~~", true),
+            vec![ScriptRun::Text("a ^ b ^ c, x^^ and ~~y~~")]
+        );
+    }
+
+    #[test]
+    fn test_script_runs_skip_emoticons() {
+        assert_eq!(
+            script_runs("ok^-^ and x^-1^", false),
+            vec![ScriptRun::Text("ok^-^ and x"), ScriptRun::Superscript("-1"),]
+        );
+    }
+
+    #[test]
+    fn test_script_runs_tilde_needs_subscript() {
+        assert_eq!(script_runs("H~2~O", false), vec![ScriptRun::Text("H~2~O")]);
+    }
+}
@@ -0,0 +1,230 @@
+use pulldown_cmark::Alignment;
+
+use crate::unicode::display_width;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableMode {
+    /// Box-drawing grid with padded columns.
+    Grid,
+    /// One `header: value` line per cell, rows separated by a blank line.
+    KeyValue,
+    /// One bullet per row with the cells joined by a middle dot.
+    Bullets,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TablePadding {
+    /// Regular spaces, aligned when the text is shown in a monospace font.
+    Monospace,
+    /// U+2007 figure spaces, which keep digit columns aligned in proportional fonts.
+    Figure,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    pub alignments: Vec<Alignment>,
+    pub head: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(alignments: Vec<Alignment>) -> Self {
+        Self {
+            alignments,
+            head: Vec::new(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Render the table as LinkedIn-friendly text (no trailing newline).
+    pub fn render(&self, mode: TableMode, padding: TablePadding, bullet: &str) -> String {
+        match mode {
+            TableMode::Grid => self.render_grid(padding),
+            TableMode::KeyValue => self.render_key_value(),
+            TableMode::Bullets => self.render_bullets(bullet),
+        }
+    }
+
+    fn column_count(&self) -> usize {
+        self.rows
+            .iter()
+            .map(Vec::len)
+            .chain(std::iter::once(self.head.len()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn render_grid(&self, padding: TablePadding) -> String {
+        let columns = self.column_count();
+        if columns == 0 {
+            return String::new();
+        }
+
+        let mut widths = vec![0usize; columns];
+        for row in std::iter::once(&self.head).chain(self.rows.iter()) {
+            for (i, cell) in row.iter().enumerate() {
+                widths[i] = widths[i].max(display_width(cell));
+            }
+        }
+
+        let pad = match padding {
+            TablePadding::Monospace => ' ',
+            TablePadding::Figure => '\u{2007}',
+        };
+
+        let mut lines = Vec::with_capacity(self.rows.len() + 4);
+        lines.push(border(&widths, '┌', '┬', '┐'));
+        if !self.head.is_empty() {
+            lines.push(self.grid_row(&self.head, &widths, pad));
+            lines.push(border(&widths, '├', '┼', '┤'));
+        }
+        for row in &self.rows {
+            lines.push(self.grid_row(row, &widths, pad));
+        }
+        lines.push(border(&widths, '└', '┴', '┘'));
+        lines.join("\n")
+    }
+
+    fn grid_row(&self, row: &[String], widths: &[usize], pad: char) -> String {
+        let mut line = String::from("│");
+        for (i, width) in widths.iter().enumerate() {
+            let cell = row.get(i).map(String::as_str).unwrap_or("");
+            let alignment = self.alignments.get(i).copied().unwrap_or(Alignment::None);
+            line.push(pad);
+            line.push_str(&align_cell(cell, *width, alignment, pad));
+            line.push(pad);
+            line.push('│');
+        }
+        line
+    }
+
+    fn render_key_value(&self) -> String {
+        let mut blocks = Vec::with_capacity(self.rows.len());
+        for row in &self.rows {
+            let lines: Vec<String> = row
+                .iter()
+                .enumerate()
+                .filter(|(_, cell)| !cell.is_empty())
+                .map(|(i, cell)| match self.head.get(i) {
+                    Some(label) if !label.is_empty() => format!("{}: {}", label, cell),
+                    _ => cell.clone(),
+                })
+                .collect();
+            if !lines.is_empty() {
+                blocks.push(lines.join("\n"));
+            }
+        }
+        blocks.join("\n\n")
+    }
+
+    fn render_bullets(&self, bullet: &str) -> String {
+        self.rows
+            .iter()
+            .map(|row| {
+                let cells: Vec<&str> = row
+                    .iter()
+                    .map(String::as_str)
+                    .filter(|cell| !cell.is_empty())
+                    .collect();
+                format!("{} {}", bullet, cells.join(" · "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn border(widths: &[usize], left: char, middle: char, right: char) -> String {
+    let mut line = String::new();
+    line.push(left);
+    for (i, width) in widths.iter().enumerate() {
+        if i > 0 {
+            line.push(middle);
+        }
+        line.extend(std::iter::repeat_n('─', width + 2));
+    }
+    line.push(right);
+    line
+}
+
+fn align_cell(cell: &str, width: usize, alignment: Alignment, pad: char) -> String {
+    let fill = width.saturating_sub(display_width(cell));
+    let (left, right) = match alignment {
+        Alignment::Right => (fill, 0),
+        Alignment::Center => (fill / 2, fill - fill / 2),
+        Alignment::Left | Alignment::None => (0, fill),
+    };
+    let mut out = String::with_capacity(cell.len() + fill * pad.len_utf8());
+    out.extend(std::iter::repeat_n(pad, left));
+    out.push_str(cell);
+    out.extend(std::iter::repeat_n(pad, right));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Table {
+        Table {
+            alignments: vec![Alignment::None, Alignment::Right],
+            head: vec!["Tool".to_string(), "Stars".to_string()],
+            rows: vec![
+                vec!["mdtolinkedin".to_string(), "42".to_string()],
+                vec!["other".to_string(), "7".to_string()],
+            ],
+        }
+    }
+
+    #[test]
+    fn test_grid_monospace() {
+        let rendered = sample().render(TableMode::Grid, TablePadding::Monospace, "•");
+        let expected = "┌──────────────┬───────┐\n\
+                        │ Tool         │ Stars │\n\
+                        ├──────────────┼───────┤\n\
+                        │ mdtolinkedin │    42 │\n\
+                        │ other        │     7 │\n\
+                        └──────────────┴───────┘";
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_grid_ignores_combining_marks() {
+        let table = Table {
+            alignments: vec![Alignment::None],
+            head: vec!["Name".to_string()],
+            rows: vec![
+                vec![crate::unicode::to_strikethrough("old")],
+                vec!["Cafe\u{0301}".to_string()],
+            ],
+        };
+        let rendered = table.render(TableMode::Grid, TablePadding::Monospace, "•");
+        let expected = format!(
+            "┌──────┐\n│ Name │\n├──────┤\n│ {}  │\n│ {} │\n└──────┘",
+            crate::unicode::to_strikethrough("old"),
+            "Cafe\u{0301}"
+        );
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_grid_figure_spaces() {
+        let rendered = sample().render(TableMode::Grid, TablePadding::Figure, "•");
+        assert!(rendered.contains("│\u{2007}other\u{2007}"));
+        assert!(!rendered.contains(' '));
+    }
+
+    #[test]
+    fn test_key_value() {
+        let rendered = sample().render(TableMode::KeyValue, TablePadding::Monospace, "•");
+        assert_eq!(
+            rendered,
+            "Tool: mdtolinkedin\nStars: 42\n\nTool: other\nStars: 7"
+        );
+    }
+
+    #[test]
+    fn test_bullets() {
+        let rendered = sample().render(TableMode::Bullets, TablePadding::Monospace, "-");
+        assert_eq!(rendered, "- mdtolinkedin · 42\n- other · 7");
+    }
+}
@@ -1,21 +1,488 @@
-/// Convert ASCII letters to Mathematical Bold Unicode.
+use unicode_normalization::char::{decompose_canonical, is_combining_mark};
+
+/// Unicode letter style families that text can be rendered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StyleFamily {
+    Normal,
+    Bold,
+    Italic,
+    BoldItalic,
+    SansSerif,
+    SansBold,
+    SansItalic,
+    SansBoldItalic,
+    Script,
+    BoldScript,
+    Fraktur,
+    BoldFraktur,
+    DoubleStruck,
+    Monospace,
+    SmallCaps,
+    Circled,
+    Squared,
+}
+
+/// Code point layout of a Mathematical Alphanumeric family.
+struct FamilyTable {
+    upper: u32,
+    lower: u32,
+    digits: Option<DigitStyle>,
+    greek: Option<u32>,
+    /// Letters whose slot in the block is reserved; the real character lives in
+    /// the Letterlike Symbols block.
+    exceptions: &'static [(char, char)],
+}
+
+const ITALIC_EXCEPTIONS: &[(char, char)] = &[('h', 'ℎ')];
+
+const SCRIPT_EXCEPTIONS: &[(char, char)] = &[
+    ('B', 'ℬ'),
+    ('E', 'ℰ'),
+    ('F', 'ℱ'),
+    ('H', 'ℋ'),
+    ('I', 'ℐ'),
+    ('L', 'ℒ'),
+    ('M', 'ℳ'),
+    ('R', 'ℛ'),
+    ('e', 'ℯ'),
+    ('g', 'ℊ'),
+    ('o', 'ℴ'),
+];
+
+const FRAKTUR_EXCEPTIONS: &[(char, char)] =
+    &[('C', 'ℭ'), ('H', 'ℌ'), ('I', 'ℑ'), ('R', 'ℜ'), ('Z', 'ℨ')];
+
+const DOUBLE_STRUCK_EXCEPTIONS: &[(char, char)] = &[
+    ('C', 'ℂ'),
+    ('H', 'ℍ'),
+    ('N', 'ℕ'),
+    ('P', 'ℙ'),
+    ('Q', 'ℚ'),
+    ('R', 'ℝ'),
+    ('Z', 'ℤ'),
+];
+
+const fn table(
+    upper: u32,
+    lower: u32,
+    digits: Option<DigitStyle>,
+    greek: Option<u32>,
+    exceptions: &'static [(char, char)],
+) -> FamilyTable {
+    FamilyTable {
+        upper,
+        lower,
+        digits,
+        greek,
+        exceptions,
+    }
+}
+
+impl StyleFamily {
+    /// Every family, in the order they are listed in help output.
+    pub const ALL: [StyleFamily; 17] = [
+        StyleFamily::Normal,
+        StyleFamily::Bold,
+        StyleFamily::Italic,
+        StyleFamily::BoldItalic,
+        StyleFamily::SansSerif,
+        StyleFamily::SansBold,
+        StyleFamily::SansItalic,
+        StyleFamily::SansBoldItalic,
+        StyleFamily::Script,
+        StyleFamily::BoldScript,
+        StyleFamily::Fraktur,
+        StyleFamily::BoldFraktur,
+        StyleFamily::DoubleStruck,
+        StyleFamily::Monospace,
+        StyleFamily::SmallCaps,
+        StyleFamily::Circled,
+        StyleFamily::Squared,
+    ];
+
+    /// Kebab-case name used in CLI specs (e.g. `bold-italic`).
+    pub fn name(self) -> &'static str {
+        match self {
+            StyleFamily::Normal => "normal",
+            StyleFamily::Bold => "bold",
+            StyleFamily::Italic => "italic",
+            StyleFamily::BoldItalic => "bold-italic",
+            StyleFamily::SansSerif => "sans",
+            StyleFamily::SansBold => "sans-bold",
+            StyleFamily::SansItalic => "sans-italic",
+            StyleFamily::SansBoldItalic => "sans-bold-italic",
+            StyleFamily::Script => "script",
+            StyleFamily::BoldScript => "bold-script",
+            StyleFamily::Fraktur => "fraktur",
+            StyleFamily::BoldFraktur => "bold-fraktur",
+            StyleFamily::DoubleStruck => "double-struck",
+            StyleFamily::Monospace => "monospace",
+            StyleFamily::SmallCaps => "small-caps",
+            StyleFamily::Circled => "circled",
+            StyleFamily::Squared => "squared",
+        }
+    }
+
+    /// Look up a family by its kebab-case name (e.g. `bold-italic`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|family| family.name() == name)
+    }
+
+    /// The family used for `**strong**` text nested inside this one.
+    pub fn bolder(self) -> Self {
+        match self {
+            StyleFamily::Normal => StyleFamily::Bold,
+            StyleFamily::Italic => StyleFamily::BoldItalic,
+            StyleFamily::SansSerif => StyleFamily::SansBold,
+            StyleFamily::SansItalic => StyleFamily::SansBoldItalic,
+            StyleFamily::Script => StyleFamily::BoldScript,
+            StyleFamily::Fraktur => StyleFamily::BoldFraktur,
+            other => other,
+        }
+    }
+
+    /// The family used for `*emphasis*` nested inside this one.
+    pub fn italicized(self) -> Self {
+        match self {
+            StyleFamily::Normal => StyleFamily::Italic,
+            StyleFamily::Bold => StyleFamily::BoldItalic,
+            StyleFamily::SansSerif => StyleFamily::SansItalic,
+            StyleFamily::SansBold => StyleFamily::SansBoldItalic,
+            other => other,
+        }
+    }
+
+    fn table(self) -> Option<FamilyTable> {
+        let t = match self {
+            StyleFamily::Bold => {
+                table(0x1D400, 0x1D41A, Some(DigitStyle::Bold), Some(0x1D6A8), &[])
+            }
+            StyleFamily::Italic => table(0x1D434, 0x1D44E, None, Some(0x1D6E2), ITALIC_EXCEPTIONS),
+            StyleFamily::BoldItalic => {
+                table(0x1D468, 0x1D482, Some(DigitStyle::Bold), Some(0x1D71C), &[])
+            }
+            StyleFamily::SansSerif => {
+                table(0x1D5A0, 0x1D5BA, Some(DigitStyle::SansSerif), None, &[])
+            }
+            StyleFamily::SansBold => table(
+                0x1D5D4,
+                0x1D5EE,
+                Some(DigitStyle::SansSerifBold),
+                Some(0x1D756),
+                &[],
+            ),
+            StyleFamily::SansItalic => table(0x1D608, 0x1D622, None, None, &[]),
+            StyleFamily::SansBoldItalic => table(
+                0x1D63C,
+                0x1D656,
+                Some(DigitStyle::SansSerifBold),
+                Some(0x1D790),
+                &[],
+            ),
+            StyleFamily::Script => table(0x1D49C, 0x1D4B6, None, None, SCRIPT_EXCEPTIONS),
+            StyleFamily::BoldScript => table(0x1D4D0, 0x1D4EA, None, None, &[]),
+            StyleFamily::Fraktur => table(0x1D504, 0x1D51E, None, None, FRAKTUR_EXCEPTIONS),
+            StyleFamily::BoldFraktur => table(0x1D56C, 0x1D586, None, None, &[]),
+            StyleFamily::DoubleStruck => table(
+                0x1D538,
+                0x1D552,
+                Some(DigitStyle::DoubleStruck),
+                None,
+                DOUBLE_STRUCK_EXCEPTIONS,
+            ),
+            StyleFamily::Monospace => {
+                table(0x1D670, 0x1D68A, Some(DigitStyle::Monospace), None, &[])
+            }
+            StyleFamily::Normal
+            | StyleFamily::SmallCaps
+            | StyleFamily::Circled
+            | StyleFamily::Squared => return None,
+        };
+        Some(t)
+    }
+
+    /// Map a single character, leaving it unchanged when the family has no
+    /// equivalent. With `digits`, ASCII digits are mapped too when the family
+    /// has a digit set (italic and script have none).
+    pub fn map_char(self, c: char, digits: bool) -> char {
+        match self {
+            StyleFamily::Normal => c,
+            StyleFamily::SmallCaps => small_cap(c),
+            StyleFamily::Circled => circled(c, digits),
+            StyleFamily::Squared => squared(c),
+            _ => match self.table() {
+                Some(t) => t.map(c, digits),
+                None => c,
+            },
+        }
+    }
+
+    /// Render text in this family, leaving letters it cannot style as they are.
+    pub fn apply(self, text: &str, digits: bool) -> String {
+        self.apply_with(text, digits, UnstyledFallback::Keep)
+    }
+
+    /// Render text in this family.
+    ///
+    /// Accented letters are decomposed (NFD) so the base letter is styled and
+    /// the combining marks are kept. Letters with no equivalent in the family,
+    /// such as Cyrillic or CJK, are grouped into runs and handled by `fallback`.
+    pub fn apply_with(self, text: &str, digits: bool, fallback: UnstyledFallback) -> String {
+        if self == StyleFamily::Normal {
+            return text.to_string();
+        }
+
+        let mut out = String::with_capacity(text.len() * 4);
+        let mut unstyled = String::new();
+        for c in text.chars() {
+            if !unstyled.is_empty() && is_combining_mark(c) {
+                unstyled.push(c);
+                continue;
+            }
+            if !c.is_ascii() && c.is_alphabetic() && !is_latin(c) && !self.can_style(c, digits) {
+                unstyled.push(c);
+                continue;
+            }
+            flush_unstyled(&mut out, &mut unstyled, fallback);
+            self.push_styled(c, digits, &mut out);
+        }
+        flush_unstyled(&mut out, &mut unstyled, fallback);
+        out
+    }
+
+    fn can_style(self, c: char, digits: bool) -> bool {
+        self.map_char(c, digits) != c
+            || base_letter(c).is_some_and(|base| self.map_char(base, digits) != base)
+    }
+
+    /// Push `c` in this family, styling the base letter of a decomposable
+    /// character and keeping its combining marks. Characters the family
+    /// cannot style are pushed unchanged, so they stay precomposed.
+    fn push_styled(self, c: char, digits: bool, out: &mut String) {
+        let mapped = self.map_char(c, digits);
+        if mapped != c || c.is_ascii() {
+            out.push(mapped);
+            return;
+        }
+
+        let mut decomposed = Vec::with_capacity(4);
+        decompose_canonical(c, |part| decomposed.push(part));
+        match decomposed.split_first() {
+            Some((&base, marks)) if self.map_char(base, digits) != base => {
+                out.push(self.map_char(base, digits));
+                out.extend(marks);
+            }
+            _ => out.push(c),
+        }
+    }
+}
+
+/// What to do with letters a style family has no equivalent for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnstyledFallback {
+    /// Leave the letters as they are.
+    Keep,
+    /// Add a combining low line so the run still stands out.
+    Underline,
+    /// Wrap each unstyled run in brackets.
+    Brackets,
+}
+
+fn flush_unstyled(out: &mut String, unstyled: &mut String, fallback: UnstyledFallback) {
+    if unstyled.is_empty() {
+        return;
+    }
+    match fallback {
+        UnstyledFallback::Keep => out.push_str(unstyled),
+        UnstyledFallback::Underline => out.push_str(&to_underline(unstyled)),
+        UnstyledFallback::Brackets => {
+            out.push('[');
+            out.push_str(unstyled);
+            out.push(']');
+        }
+    }
+    unstyled.clear();
+}
+
+/// Latin letters beyond ASCII, like ß, œ or ł. The fallback is meant for
+/// other scripts, so these stay inline even when a family can't style them.
+fn is_latin(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00C0}'..='\u{024F}'
+            | '\u{0250}'..='\u{02AF}'
+            | '\u{1D00}'..='\u{1DBF}'
+            | '\u{1E00}'..='\u{1EFF}'
+            | '\u{2C60}'..='\u{2C7F}'
+            | '\u{A720}'..='\u{A7FF}'
+            | '\u{AB30}'..='\u{AB6F}'
+            | '\u{FB00}'..='\u{FB06}'
+            | '\u{FF21}'..='\u{FF3A}'
+            | '\u{FF41}'..='\u{FF5A}'
+    )
+}
+
+/// First character of the canonical decomposition, if `c` decomposes.
+fn base_letter(c: char) -> Option<char> {
+    let mut base = None;
+    decompose_canonical(c, |part| {
+        if base.is_none() {
+            base = Some(part);
+        }
+    });
+    base.filter(|base| *base != c)
+}
+
+impl FamilyTable {
+    fn map(&self, c: char, digits: bool) -> char {
+        if let Some((_, mapped)) = self.exceptions.iter().find(|(from, _)| *from == c) {
+            return *mapped;
+        }
+        let code = match c {
+            'A'..='Z' => self.upper + (c as u32 - 'A' as u32),
+            'a'..='z' => self.lower + (c as u32 - 'a' as u32),
+            '0'..='9' => match self.digits {
+                Some(style) if digits => style.base() + (c as u32 - '0' as u32),
+                _ => return c,
+            },
+            _ => match (self.greek, greek_offset(c)) {
+                (Some(base), Some(offset)) => base + offset,
+                _ => return c,
+            },
+        };
+        char::from_u32(code).unwrap_or(c)
+    }
+}
+
+fn small_cap(c: char) -> char {
+    match c {
+        'a' => 'ᴀ',
+        'b' => 'ʙ',
+        'c' => 'ᴄ',
+        'd' => 'ᴅ',
+        'e' => 'ᴇ',
+        'f' => 'ꜰ',
+        'g' => 'ɢ',
+        'h' => 'ʜ',
+        'i' => 'ɪ',
+        'j' => 'ᴊ',
+        'k' => 'ᴋ',
+        'l' => 'ʟ',
+        'm' => 'ᴍ',
+        'n' => 'ɴ',
+        'o' => 'ᴏ',
+        'p' => 'ᴘ',
+        'q' => 'ꞯ',
+        'r' => 'ʀ',
+        's' => 'ꜱ',
+        't' => 'ᴛ',
+        'u' => 'ᴜ',
+        'v' => 'ᴠ',
+        'w' => 'ᴡ',
+        'y' => 'ʏ',
+        'z' => 'ᴢ',
+        _ => c,
+    }
+}
+
+fn circled(c: char, digits: bool) -> char {
+    let code = match c {
+        'A'..='Z' => 0x24B6 + (c as u32 - 'A' as u32),
+        'a'..='z' => 0x24D0 + (c as u32 - 'a' as u32),
+        '0' if digits => 0x24EA,
+        '1'..='9' if digits => 0x2460 + (c as u32 - '1' as u32),
+        _ => return c,
+    };
+    char::from_u32(code).unwrap_or(c)
+}
+
+fn squared(c: char) -> char {
+    let code = match c {
+        'A'..='Z' => 0x1F130 + (c as u32 - 'A' as u32),
+        'a'..='z' => 0x1F130 + (c as u32 - 'a' as u32),
+        _ => return c,
+    };
+    char::from_u32(code).unwrap_or(c)
+}
+
+/// Mathematical Alphanumeric digit families.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigitStyle {
+    /// 𝟎𝟏𝟐 (U+1D7CE)
+    Bold,
+    /// 𝟘𝟙𝟚 (U+1D7D8)
+    DoubleStruck,
+    /// 𝟢𝟣𝟤 (U+1D7E2)
+    SansSerif,
+    /// 𝟬𝟭𝟮 (U+1D7EC)
+    SansSerifBold,
+    /// 𝟶𝟷𝟸 (U+1D7F6)
+    Monospace,
+}
+
+impl DigitStyle {
+    const fn base(self) -> u32 {
+        match self {
+            DigitStyle::Bold => 0x1D7CE,
+            DigitStyle::DoubleStruck => 0x1D7D8,
+            DigitStyle::SansSerif => 0x1D7E2,
+            DigitStyle::SansSerifBold => 0x1D7EC,
+            DigitStyle::Monospace => 0x1D7F6,
+        }
+    }
+}
+
+/// Convert ASCII digits to a Mathematical Alphanumeric digit family.
 ///
 /// # Example
 /// ```
-/// use mdtolinkedin::unicode::to_bold;
-/// assert_eq!(to_bold("Hello"), "𝐇𝐞𝐥𝐥𝐨");
+/// use mdtolinkedin::unicode::{to_styled_digits, DigitStyle};
+/// assert_eq!(to_styled_digits("Top 10", DigitStyle::Bold), "Top 𝟏𝟎");
 /// ```
-pub fn to_bold(text: &str) -> String {
+pub fn to_styled_digits(text: &str, style: DigitStyle) -> String {
     text.chars()
         .map(|c| match c {
-            'A'..='Z' => char::from_u32(0x1D400 + (c as u32 - 'A' as u32)).unwrap_or(c),
-            'a'..='z' => char::from_u32(0x1D41A + (c as u32 - 'a' as u32)).unwrap_or(c),
+            '0'..='9' => char::from_u32(style.base() + (c as u32 - '0' as u32)).unwrap_or(c),
             _ => c,
         })
         .collect()
 }
 
-/// Convert ASCII letters to Mathematical Italic Unicode.
+/// Offset of a Greek letter within a Mathematical Greek block.
+///
+/// Each block holds the 25 capitals (with ϴ in the gap at U+03A2), ∇, the 25
+/// lowercase letters (including final sigma), ∂, and the variants ϵ ϑ ϰ ϕ ϱ ϖ.
+fn greek_offset(c: char) -> Option<u32> {
+    let offset = match c {
+        'Α'..='Ρ' => c as u32 - 'Α' as u32,
+        'ϴ' => 17,
+        'Σ'..='Ω' => c as u32 - 'Α' as u32,
+        '∇' => 25,
+        'α'..='ω' => 26 + (c as u32 - 'α' as u32),
+        '∂' => 51,
+        'ϵ' => 52,
+        'ϑ' => 53,
+        'ϰ' => 54,
+        'ϕ' => 55,
+        'ϱ' => 56,
+        'ϖ' => 57,
+        _ => return None,
+    };
+    Some(offset)
+}
+
+/// Convert ASCII and Greek letters to Mathematical Bold Unicode.
+///
+/// # Example
+/// ```
+/// use mdtolinkedin::unicode::to_bold;
+/// assert_eq!(to_bold("Hello"), "𝐇𝐞𝐥𝐥𝐨");
+/// ```
+pub fn to_bold(text: &str) -> String {
+    StyleFamily::Bold.apply(text, false)
+}
+
+/// Convert ASCII and Greek letters to Mathematical Italic Unicode.
 ///
 /// # Example
 /// ```
@@ -23,27 +490,235 @@ pub fn to_bold(text: &str) -> String {
 /// assert_eq!(to_italic("Hello"), "𝐻𝑒𝑙𝑙𝑜");
 /// ```
 pub fn to_italic(text: &str) -> String {
-    text.chars()
-        .map(|c| match c {
-            'A'..='Z' => char::from_u32(0x1D434 + (c as u32 - 'A' as u32)).unwrap_or(c),
-            'a'..='z' => char::from_u32(0x1D44E + (c as u32 - 'a' as u32)).unwrap_or(c),
-            _ => c,
-        })
-        .collect()
+    StyleFamily::Italic.apply(text, false)
 }
 
-/// Convert ASCII letters to Mathematical Bold Italic Unicode.
-#[allow(dead_code)]
+/// Convert ASCII and Greek letters to Mathematical Bold Italic Unicode.
 pub fn to_bold_italic(text: &str) -> String {
+    StyleFamily::BoldItalic.apply(text, false)
+}
+
+/// Convert ASCII letters and digits to Mathematical Monospace Unicode.
+///
+/// # Example
+/// ```
+/// use mdtolinkedin::unicode::to_monospace;
+/// assert_eq!(to_monospace("fn(1)"), "𝚏𝚗(𝟷)");
+/// ```
+pub fn to_monospace(text: &str) -> String {
+    StyleFamily::Monospace.apply(text, true)
+}
+
+/// Strike through text by following each character with U+0336 (combining long stroke).
+///
+/// # Example
+/// ```
+/// use mdtolinkedin::unicode::to_strikethrough;
+/// assert_eq!(to_strikethrough("no"), "n\u{0336}o\u{0336}");
+/// ```
+pub fn to_strikethrough(text: &str) -> String {
+    combine_each(text, '\u{0336}')
+}
+
+/// Underline text by following each character with U+0332 (combining low line).
+///
+/// # Example
+/// ```
+/// use mdtolinkedin::unicode::to_underline;
+/// assert_eq!(to_underline("ok"), "o\u{0332}k\u{0332}");
+/// ```
+pub fn to_underline(text: &str) -> String {
+    combine_each(text, '\u{0332}')
+}
+
+/// Number of characters a reader sees: combining marks, such as those added
+/// by [`to_strikethrough`] and [`to_underline`] or a decomposed accent, sit on
+/// the character before them and take no column of their own.
+///
+/// # Example
+/// ```
+/// use mdtolinkedin::unicode::{display_width, to_strikethrough};
+/// assert_eq!(display_width(&to_strikethrough("no")), 2);
+/// assert_eq!(display_width("Cafe\u{0301}"), 4);
+/// ```
+pub fn display_width(text: &str) -> usize {
+    text.chars().filter(|&c| !is_combining_mark(c)).count()
+}
+
+/// Convert ASCII digits to Unicode superscript digits (⁰¹²³⁴⁵⁶⁷⁸⁹).
+///
+/// # Example
+/// ```
+/// use mdtolinkedin::unicode::to_superscript_digits;
+/// assert_eq!(to_superscript_digits("12"), "¹²");
+/// ```
+pub fn to_superscript_digits(text: &str) -> String {
     text.chars()
         .map(|c| match c {
-            'A'..='Z' => char::from_u32(0x1D468 + (c as u32 - 'A' as u32)).unwrap_or(c),
-            'a'..='z' => char::from_u32(0x1D482 + (c as u32 - 'a' as u32)).unwrap_or(c),
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '0' | '4'..='9' => char::from_u32(0x2070 + (c as u32 - '0' as u32)).unwrap_or(c),
             _ => c,
         })
         .collect()
 }
 
+/// Superscript form of a character, if Unicode has one.
+pub fn superscript_char(c: char) -> Option<char> {
+    let mapped = match c {
+        '0' => '⁰',
+        '1' => '¹',
+        '2' => '²',
+        '3' => '³',
+        '4'..='9' => return char::from_u32(0x2074 + (c as u32 - '4' as u32)),
+        '+' => '⁺',
+        '-' | '−' => '⁻',
+        '=' => '⁼',
+        '(' => '⁽',
+        ')' => '⁾',
+        'a' => 'ᵃ',
+        'b' => 'ᵇ',
+        'c' => 'ᶜ',
+        'd' => 'ᵈ',
+        'e' => 'ᵉ',
+        'f' => 'ᶠ',
+        'g' => 'ᵍ',
+        'h' => 'ʰ',
+        'i' => 'ⁱ',
+        'j' => 'ʲ',
+        'k' => 'ᵏ',
+        'l' => 'ˡ',
+        'm' => 'ᵐ',
+        'n' => 'ⁿ',
+        'o' => 'ᵒ',
+        'p' => 'ᵖ',
+        'r' => 'ʳ',
+        's' => 'ˢ',
+        't' => 'ᵗ',
+        'u' => 'ᵘ',
+        'v' => 'ᵛ',
+        'w' => 'ʷ',
+        'x' => 'ˣ',
+        'y' => 'ʸ',
+        'z' => 'ᶻ',
+        'A' => 'ᴬ',
+        'B' => 'ᴮ',
+        'D' => 'ᴰ',
+        'E' => 'ᴱ',
+        'G' => 'ᴳ',
+        'H' => 'ᴴ',
+        'I' => 'ᴵ',
+        'J' => 'ᴶ',
+        'K' => 'ᴷ',
+        'L' => 'ᴸ',
+        'M' => 'ᴹ',
+        'N' => 'ᴺ',
+        'O' => 'ᴼ',
+        'P' => 'ᴾ',
+        'R' => 'ᴿ',
+        'T' => 'ᵀ',
+        'U' => 'ᵁ',
+        'V' => 'ⱽ',
+        'W' => 'ᵂ',
+        'α' => 'ᵅ',
+        'β' => 'ᵝ',
+        'γ' => 'ᵞ',
+        'δ' => 'ᵟ',
+        'ε' => 'ᵋ',
+        'θ' => 'ᶿ',
+        'ι' => 'ᶥ',
+        'φ' => 'ᵠ',
+        'χ' => 'ᵡ',
+        _ if c.is_whitespace() => c,
+        _ => return None,
+    };
+    Some(mapped)
+}
+
+/// Subscript form of a character, if Unicode has one.
+pub fn subscript_char(c: char) -> Option<char> {
+    let mapped = match c {
+        '0'..='9' => return char::from_u32(0x2080 + (c as u32 - '0' as u32)),
+        '+' => '₊',
+        '-' | '−' => '₋',
+        '=' => '₌',
+        '(' => '₍',
+        ')' => '₎',
+        'a' => 'ₐ',
+        'e' => 'ₑ',
+        'h' => 'ₕ',
+        'i' => 'ᵢ',
+        'j' => 'ⱼ',
+        'k' => 'ₖ',
+        'l' => 'ₗ',
+        'm' => 'ₘ',
+        'n' => 'ₙ',
+        'o' => 'ₒ',
+        'p' => 'ₚ',
+        'r' => 'ᵣ',
+        's' => 'ₛ',
+        't' => 'ₜ',
+        'u' => 'ᵤ',
+        'v' => 'ᵥ',
+        'x' => 'ₓ',
+        'β' => 'ᵦ',
+        'γ' => 'ᵧ',
+        'ρ' => 'ᵨ',
+        'φ' => 'ᵩ',
+        'χ' => 'ᵪ',
+        _ if c.is_whitespace() => c,
+        _ => return None,
+    };
+    Some(mapped)
+}
+
+/// Raise text with Unicode superscripts.
+///
+/// Unicode only has superscript forms for some letters, so when any
+/// character in `text` lacks one the whole run is written as `^(text)`
+/// instead of mixing raised and normal letters.
+///
+/// # Example
+/// ```
+/// use mdtolinkedin::unicode::to_superscript;
+/// assert_eq!(to_superscript("2nd"), "²ⁿᵈ");
+/// assert_eq!(to_superscript("XYZ"), "^(XYZ)");
+/// ```
+pub fn to_superscript(text: &str) -> String {
+    text.chars()
+        .map(superscript_char)
+        .collect::<Option<String>>()
+        .unwrap_or_else(|| format!("^({})", text))
+}
+
+/// Lower text with Unicode subscripts, or write it as `_(text)` when any
+/// character has no subscript form.
+///
+/// # Example
+/// ```
+/// use mdtolinkedin::unicode::to_subscript;
+/// assert_eq!(to_subscript("2"), "₂");
+/// assert_eq!(to_subscript("big"), "_(big)");
+/// ```
+pub fn to_subscript(text: &str) -> String {
+    text.chars()
+        .map(subscript_char)
+        .collect::<Option<String>>()
+        .unwrap_or_else(|| format!("_({})", text))
+}
+
+fn combine_each(text: &str, mark: char) -> String {
+    let mut out = String::with_capacity(text.len() * 3);
+    for c in text.chars() {
+        out.push(c);
+        if !c.is_control() {
+            out.push(mark);
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,11 +743,51 @@ mod tests {
         assert_eq!(to_bold("Test123"), "𝐓𝐞𝐬𝐭123");
     }
 
+    #[test]
+    fn test_bold_numbers_styled() {
+        assert_eq!(StyleFamily::Bold.apply("Test123", true), "𝐓𝐞𝐬𝐭𝟏𝟐𝟑");
+    }
+
+    #[test]
+    fn test_bold_numbers_unstyled_when_disabled() {
+        assert_eq!(StyleFamily::Bold.apply("Test123", false), "𝐓𝐞𝐬𝐭123");
+    }
+
+    #[test]
+    fn test_italic_numbers_unchanged() {
+        assert_eq!(StyleFamily::Italic.apply("A1", true), "𝐴1");
+    }
+
+    #[test]
+    fn test_digit_families() {
+        assert_eq!(to_styled_digits("09", DigitStyle::Bold), "𝟎𝟗");
+        assert_eq!(to_styled_digits("09", DigitStyle::DoubleStruck), "𝟘𝟡");
+        assert_eq!(to_styled_digits("09", DigitStyle::SansSerif), "𝟢𝟫");
+        assert_eq!(to_styled_digits("09", DigitStyle::SansSerifBold), "𝟬𝟵");
+        assert_eq!(to_styled_digits("09", DigitStyle::Monospace), "𝟶𝟿");
+    }
+
+    #[test]
+    fn test_bold_greek() {
+        assert_eq!(to_bold("ΑΩαωϴ∇"), "𝚨𝛀𝛂𝛚𝚹𝛁");
+    }
+
+    #[test]
+    fn test_italic_greek() {
+        assert_eq!(to_italic("Δπ"), "𝛥𝜋");
+    }
+
+    #[test]
+    fn test_bold_italic_greek_variants() {
+        assert_eq!(to_bold_italic("ςϖ"), "𝝇𝝕");
+    }
+
     #[test]
     fn test_italic_lowercase() {
+        // Italic small h is U+210E; U+1D455 is reserved.
         assert_eq!(
             to_italic("hello"),
-            "\u{1D455}\u{1D452}\u{1D459}\u{1D459}\u{1D45C}"
+            "\u{210E}\u{1D452}\u{1D459}\u{1D459}\u{1D45C}"
         );
     }
 
@@ -90,4 +805,184 @@ mod tests {
     fn test_preserves_punctuation() {
         assert_eq!(to_bold("Hello, World!"), "𝐇𝐞𝐥𝐥𝐨, 𝐖𝐨𝐫𝐥𝐝!");
     }
+
+    #[test]
+    fn test_strikethrough_spaces() {
+        assert_eq!(to_strikethrough("a b"), "a\u{0336} \u{0336}b\u{0336}");
+    }
+
+    #[test]
+    fn test_underline_keeps_newlines_clean() {
+        assert_eq!(to_underline("a\nb"), "a\u{0332}\nb\u{0332}");
+    }
+
+    #[test]
+    fn test_strikethrough_bold_composes() {
+        assert_eq!(to_strikethrough(&to_bold("A")), "𝐀\u{0336}");
+    }
+
+    #[test]
+    fn test_superscript_digits() {
+        assert_eq!(to_superscript_digits("1234567890"), "¹²³⁴⁵⁶⁷⁸⁹⁰");
+        assert_eq!(to_superscript("1234567890"), "¹²³⁴⁵⁶⁷⁸⁹⁰");
+    }
+
+    #[test]
+    fn test_style_family_from_name() {
+        assert_eq!(
+            StyleFamily::from_name("bold-italic"),
+            Some(StyleFamily::BoldItalic)
+        );
+        assert_eq!(StyleFamily::from_name("gothic"), None);
+    }
+
+    #[test]
+    fn test_monospace_letters_and_digits() {
+        assert_eq!(to_monospace("AZaz09"), "𝙰𝚉𝚊𝚣𝟶𝟿");
+    }
+
+    #[test]
+    fn test_monospace_preserves_punctuation() {
+        assert_eq!(to_monospace("println!(\"{}\");"), "𝚙𝚛𝚒𝚗𝚝𝚕𝚗!(\"{}\");");
+    }
+
+    /// Reserved (unassigned) code points inside the Mathematical Alphanumeric block.
+    const RESERVED: &[u32] = &[
+        0x1D455, 0x1D49D, 0x1D4A0, 0x1D4A1, 0x1D4A3, 0x1D4A4, 0x1D4A7, 0x1D4A8, 0x1D4AD, 0x1D4BA,
+        0x1D4BC, 0x1D4C4, 0x1D506, 0x1D50B, 0x1D50C, 0x1D515, 0x1D51D, 0x1D53A, 0x1D53F, 0x1D545,
+        0x1D547, 0x1D548, 0x1D549, 0x1D551, 0x1D6A6, 0x1D6A7, 0x1D7CC, 0x1D7CD,
+    ];
+
+    #[test]
+    fn test_no_family_emits_reserved_code_points() {
+        let input: String = ('A'..='Z').chain('a'..='z').chain('0'..='9').collect();
+        for family in StyleFamily::ALL {
+            for c in family.apply(&input, true).chars() {
+                assert!(
+                    !RESERVED.contains(&(c as u32)),
+                    "{} produced reserved U+{:X}",
+                    family.name(),
+                    c as u32
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_family_names_round_trip() {
+        for family in StyleFamily::ALL {
+            assert_eq!(StyleFamily::from_name(family.name()), Some(family));
+        }
+    }
+
+    #[test]
+    fn test_script_exceptions() {
+        assert_eq!(
+            StyleFamily::Script.apply("BEFHILMRego", false),
+            "ℬℰℱℋℐℒℳℛℯℊℴ"
+        );
+        assert_eq!(StyleFamily::Script.apply("Ab", false), "𝒜𝒷");
+    }
+
+    #[test]
+    fn test_fraktur_exceptions() {
+        assert_eq!(StyleFamily::Fraktur.apply("CHIRZa", false), "ℭℌℑℜℨ𝔞");
+    }
+
+    #[test]
+    fn test_double_struck() {
+        assert_eq!(StyleFamily::DoubleStruck.apply("CNRx1", true), "ℂℕℝ𝕩𝟙");
+    }
+
+    #[test]
+    fn test_sans_families() {
+        assert_eq!(StyleFamily::SansSerif.apply("Ab1", true), "𝖠𝖻𝟣");
+        assert_eq!(StyleFamily::SansBold.apply("Ab1", true), "𝗔𝗯𝟭");
+        assert_eq!(StyleFamily::SansItalic.apply("Ab", false), "𝘈𝘣");
+        assert_eq!(StyleFamily::SansBoldItalic.apply("Ab", false), "𝘼𝙗");
+    }
+
+    #[test]
+    fn test_small_caps_circled_squared() {
+        assert_eq!(StyleFamily::SmallCaps.apply("Hello", false), "Hᴇʟʟᴏ");
+        assert_eq!(StyleFamily::Circled.apply("Ab1", true), "Ⓐⓑ①");
+        assert_eq!(StyleFamily::Squared.apply("ok", false), "🄾🄺");
+    }
+
+    #[test]
+    fn test_bolder_and_italicized() {
+        assert_eq!(StyleFamily::Script.bolder(), StyleFamily::BoldScript);
+        assert_eq!(
+            StyleFamily::SansBold.italicized(),
+            StyleFamily::SansBoldItalic
+        );
+    }
+
+    #[test]
+    fn test_bold_accented_latin() {
+        assert_eq!(to_bold("Canción"), "𝐂𝐚𝐧𝐜𝐢𝐨\u{0301}𝐧");
+        assert_eq!(to_bold("Über"), "𝐔\u{0308}𝐛𝐞𝐫");
+    }
+
+    #[test]
+    fn test_italic_accented_greek() {
+        assert_eq!(to_italic("ά"), "𝛼\u{0301}");
+    }
+
+    #[test]
+    fn test_unstyled_letters_kept_precomposed() {
+        assert_eq!(to_bold("ß ø"), "ß ø");
+    }
+
+    #[test]
+    fn test_fallback_keep_cyrillic() {
+        assert_eq!(to_bold("Hi Мир"), "𝐇𝐢 Мир");
+    }
+
+    #[test]
+    fn test_fallback_underline() {
+        let styled = StyleFamily::Bold.apply_with("A 東京", false, UnstyledFallback::Underline);
+        assert_eq!(styled, "𝐀 東\u{0332}京\u{0332}");
+    }
+
+    #[test]
+    fn test_fallback_brackets() {
+        let styled = StyleFamily::Bold.apply_with("Привет мир!", false, UnstyledFallback::Brackets);
+        assert_eq!(styled, "[Привет] [мир]!");
+    }
+
+    #[test]
+    fn test_fallback_keeps_latin_letters_inline() {
+        let styled =
+            StyleFamily::Bold.apply_with("Straße Œuvre łódź", false, UnstyledFallback::Brackets);
+        assert!(!styled.contains(['[', ']']));
+        assert!(styled.contains('ß'));
+        assert!(styled.contains('Œ'));
+        assert!(styled.starts_with("𝐒𝐭𝐫𝐚ß𝐞"));
+    }
+
+    #[test]
+    fn test_fallback_greek_in_script() {
+        let styled = StyleFamily::Script.apply_with("α", false, UnstyledFallback::Brackets);
+        assert_eq!(styled, "[α]");
+    }
+
+    #[test]
+    fn test_superscript_letters() {
+        assert_eq!(to_superscript("n+1"), "ⁿ⁺¹");
+        assert_eq!(to_superscript("TM"), "ᵀᴹ");
+    }
+
+    #[test]
+    fn test_subscript_letters() {
+        assert_eq!(to_subscript("i-1"), "ᵢ₋₁");
+        assert_eq!(to_subscript("(x)"), "₍ₓ₎");
+    }
+
+    #[test]
+    fn test_script_fallback_covers_whole_run() {
+        // q has no superscript form, so nothing in the run is raised
+        assert_eq!(to_superscript("qa"), "^(qa)");
+        assert_eq!(to_subscript("b2"), "_(b2)");
+    }
 }
@@ -0,0 +1,304 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::carbon::percent_encode;
+use crate::wikilink::slug;
+
+/// Click-tracking query parameters dropped by `UrlRewrite::clean`, on top of
+/// every `utm_*` parameter.
+const TRACKING_PARAMS: &[&str] = &[
+    "fbclid", "gclid", "dclid", "gbraid", "wbraid", "msclkid", "mc_eid", "igshid",
+];
+
+/// How link and image URLs are rewritten before they are shown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlRewrite {
+    /// Drop tracking parameters, lowercase the scheme and host, drop default
+    /// ports and write international hosts in punycode.
+    pub clean: bool,
+    /// `utm_*` parameters to add. Values may use `{key}` placeholders filled
+    /// from front matter, where `{slug}` falls back to the slugged `title`.
+    pub utm: Vec<(String, String)>,
+    /// When not empty, only these domains and their subdomains get UTM tags.
+    pub allow: Vec<String>,
+    /// Domains whose URLs, subdomains included, are never rewritten.
+    pub deny: Vec<String>,
+}
+
+impl Default for UrlRewrite {
+    fn default() -> Self {
+        Self {
+            clean: true,
+            utm: Vec::new(),
+            allow: Vec::new(),
+            deny: Vec::new(),
+        }
+    }
+}
+
+impl UrlRewrite {
+    /// UTM parameters for a document: the configured ones, overridden or
+    /// extended by `utm_*` front matter fields, with placeholders filled in.
+    /// A parameter whose placeholder has no value is left out.
+    pub fn tags(&self, front_matter: &HashMap<String, String>) -> Vec<(String, String)> {
+        let mut tags = self.utm.clone();
+        let mut fields: Vec<_> = front_matter
+            .iter()
+            .filter(|(key, _)| key.starts_with("utm_"))
+            .collect();
+        fields.sort();
+        for (key, value) in fields {
+            match tags.iter_mut().find(|(known, _)| known == key) {
+                Some(tag) => tag.1 = value.clone(),
+                None => tags.push((key.clone(), value.clone())),
+            }
+        }
+        tags.into_iter()
+            .filter_map(|(key, value)| Some((key, fill_placeholders(&value, front_matter)?)))
+            .collect()
+    }
+}
+
+fn fill_placeholders(value: &str, front_matter: &HashMap<String, String>) -> Option<String> {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find('{') {
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        let key = &rest[start + 1..start + len];
+        let filled = match front_matter.get(key) {
+            Some(field) => field.clone(),
+            None if key == "slug" => slug(front_matter.get("title")?),
+            None => return None,
+        };
+        out.push_str(&rest[..start]);
+        out.push_str(&filled);
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    Some(out)
+}
+
+/// Apply `rules` and the UTM `tags` to an `http(s)` URL; other URLs, and URLs
+/// on a denied domain, are returned unchanged.
+///
+/// # Example
+/// ```
+/// use mdtolinkedin::urls::{rewrite, UrlRewrite};
+/// let tags = [("utm_source".to_string(), "linkedin".to_string())];
+/// let url = rewrite("HTTPS://Bücher.example:443/a?utm_medium=x&id=7&fbclid=abc", &UrlRewrite::default(), &tags);
+/// assert_eq!(url, "https://xn--bcher-kva.example/a?id=7&utm_source=linkedin");
+/// ```
+pub fn rewrite<'a>(url: &'a str, rules: &UrlRewrite, tags: &[(String, String)]) -> Cow<'a, str> {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return Cow::Borrowed(url);
+    };
+    let lower_scheme = scheme.to_ascii_lowercase();
+    if lower_scheme != "http" && lower_scheme != "https" {
+        return Cow::Borrowed(url);
+    }
+    let (rest, fragment) = match rest.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (rest, None),
+    };
+    let (rest, query) = match rest.split_once('?') {
+        Some((rest, query)) => (rest, query),
+        None => (rest, ""),
+    };
+    let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    let (userinfo, host_port) = match authority.rsplit_once('@') {
+        Some((userinfo, host_port)) => (Some(userinfo), host_port),
+        None => (None, authority),
+    };
+    let (host, port) = split_port(host_port);
+    let domain = host.trim_end_matches('.').to_lowercase();
+    let ascii_domain = to_ascii_host(&domain);
+
+    if on_domains(&domain, &ascii_domain, &rules.deny) {
+        return Cow::Borrowed(url);
+    }
+    let tag = !tags.is_empty()
+        && (rules.allow.is_empty() || on_domains(&domain, &ascii_domain, &rules.allow));
+    if !rules.clean && !tag {
+        return Cow::Borrowed(url);
+    }
+
+    let mut params: Vec<Cow<str>> = query
+        .split('&')
+        .filter(|param| !param.is_empty())
+        .filter(|param| !(rules.clean && is_tracking(param_name(param))))
+        .filter(|param| !(tag && tags.iter().any(|(key, _)| key == param_name(param))))
+        .map(Cow::Borrowed)
+        .collect();
+    if tag {
+        params.extend(tags.iter().map(|(key, value)| {
+            Cow::Owned(format!("{}={}", percent_encode(key), percent_encode(value)))
+        }));
+    }
+
+    let mut out = String::with_capacity(url.len() + 32);
+    out.push_str(if rules.clean { &lower_scheme } else { scheme });
+    out.push_str("://");
+    if let Some(userinfo) = userinfo {
+        out.push_str(userinfo);
+        out.push('@');
+    }
+    out.push_str(if rules.clean { &ascii_domain } else { host });
+    if let Some(port) = port {
+        let default_port = matches!(
+            (lower_scheme.as_str(), port),
+            ("http", "80") | ("https", "443")
+        );
+        if !(rules.clean && default_port) {
+            out.push(':');
+            out.push_str(port);
+        }
+    }
+    out.push_str(path);
+    if !params.is_empty() {
+        out.push('?');
+        out.push_str(&params.join("&"));
+    }
+    if let Some(fragment) = fragment {
+        out.push('#');
+        out.push_str(fragment);
+    }
+
+    if out == url {
+        Cow::Borrowed(url)
+    } else {
+        Cow::Owned(out)
+    }
+}
+
+/// `host:port` → (`host`, `port`), leaving IPv6 literals like `[::1]` whole.
+fn split_port(host_port: &str) -> (&str, Option<&str>) {
+    let host_end = if host_port.starts_with('[') {
+        host_port.find(']').map_or(host_port.len(), |end| end + 1)
+    } else {
+        host_port.find(':').unwrap_or(host_port.len())
+    };
+    let (host, port) = host_port.split_at(host_end);
+    (host, port.strip_prefix(':'))
+}
+
+fn param_name(param: &str) -> &str {
+    param.split_once('=').map_or(param, |(name, _)| name)
+}
+
+fn is_tracking(name: &str) -> bool {
+    name.starts_with("utm_") || TRACKING_PARAMS.contains(&name)
+}
+
+/// Whether a domain, given in Unicode and ASCII form, is one of `rules` or
+/// a subdomain of one.
+fn on_domains(domain: &str, ascii_domain: &str, rules: &[String]) -> bool {
+    rules.iter().any(|rule| {
+        let rule = rule
+            .trim_start_matches("*.")
+            .trim_matches('.')
+            .to_lowercase();
+        [domain, ascii_domain].iter().any(|candidate| {
+            *candidate == rule
+                || candidate
+                    .strip_suffix(rule.as_str())
+                    .is_some_and(|sub| sub.ends_with('.'))
+        })
+    })
+}
+
+/// `bücher.example` → `xn--bcher-kva.example`, with UTS-46 mapping, so
+/// fullwidth letters become ASCII. Hosts IDNA rejects are left as written.
+fn to_ascii_host(host: &str) -> String {
+    idna::domain_to_ascii(host).unwrap_or_else(|_| host.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clean(url: &str) -> Cow<'_, str> {
+        rewrite(url, &UrlRewrite::default(), &[])
+    }
+
+    #[test]
+    fn test_to_ascii_host() {
+        assert_eq!(
+            to_ascii_host("bücher.例え.jp"),
+            "xn--bcher-kva.xn--r8jz45g.jp"
+        );
+        assert_eq!(to_ascii_host("ｅｘａｍｐｌｅ.com"), "example.com");
+        assert!(to_ascii_host(&"ü".repeat(300)).starts_with("xn--"));
+        assert_eq!(to_ascii_host("xn--a.example"), "xn--a.example");
+    }
+
+    #[test]
+    fn test_clean_leaves_tidy_urls_alone() {
+        for url in [
+            "https://example.com",
+            "https://example.com/a?id=1#top",
+            "mailto:me@example.com",
+            "/relative?utm_source=x",
+            "http://[::1]:8080/x",
+        ] {
+            assert!(matches!(clean(url), Cow::Borrowed(_)), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_clean_drops_tracking_and_default_port() {
+        assert_eq!(
+            clean("http://WWW.Example.com:80/Path?gclid=1&utm_source=x#Frag"),
+            "http://www.example.com/Path#Frag"
+        );
+    }
+
+    #[test]
+    fn test_allow_and_deny_domains() {
+        let rules = UrlRewrite {
+            allow: vec!["example.com".to_string()],
+            deny: vec!["keep.example.com".to_string()],
+            ..UrlRewrite::default()
+        };
+        let tags = [("utm_source".to_string(), "linkedin".to_string())];
+        assert_eq!(
+            rewrite("https://blog.example.com/p", &rules, &tags),
+            "https://blog.example.com/p?utm_source=linkedin"
+        );
+        assert_eq!(
+            rewrite("https://other.org/p?fbclid=1", &rules, &tags),
+            "https://other.org/p"
+        );
+        assert_eq!(
+            rewrite("https://keep.example.com/p?fbclid=1", &rules, &tags),
+            "https://keep.example.com/p?fbclid=1"
+        );
+        assert_eq!(
+            rewrite("https://notexample.com/", &rules, &tags),
+            "https://notexample.com/"
+        );
+    }
+
+    #[test]
+    fn test_tags_from_front_matter() {
+        let rules = UrlRewrite {
+            utm: vec![
+                ("utm_source".to_string(), "linkedin".to_string()),
+                ("utm_campaign".to_string(), "{slug}".to_string()),
+                ("utm_content".to_string(), "{missing}".to_string()),
+            ],
+            ..UrlRewrite::default()
+        };
+        let mut front_matter = HashMap::new();
+        front_matter.insert("title".to_string(), "Launch Day!".to_string());
+        front_matter.insert("utm_source".to_string(), "newsletter".to_string());
+        assert_eq!(
+            rules.tags(&front_matter),
+            vec![
+                ("utm_source".to_string(), "newsletter".to_string()),
+                ("utm_campaign".to_string(), "launch-day".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use crate::carbon::percent_encode;
+
+/// Public URL for a `[[Note]]` or `[[Note#Heading]]` wikilink target.
+///
+/// The mapping (note name → URL) wins, matched exactly and then ignoring
+/// case; otherwise the template is filled in, where `{name}` is the
+/// percent-encoded note name and `{slug}` is the lowercase, hyphenated form.
+/// A `#Heading` part is appended as a slugged fragment.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use mdtolinkedin::wikilink::resolve;
+/// let url = resolve("Deploy Notes#Step 2", Some("https://example.com/{slug}"), &HashMap::new());
+/// assert_eq!(url.as_deref(), Some("https://example.com/deploy-notes#step-2"));
+/// ```
+pub fn resolve(
+    target: &str,
+    template: Option<&str>,
+    urls: &HashMap<String, String>,
+) -> Option<String> {
+    let (name, heading) = match target.split_once('#') {
+        Some((name, heading)) => (name.trim(), Some(heading.trim())),
+        None => (target.trim(), None),
+    };
+    if name.is_empty() {
+        return None;
+    }
+
+    let mut url = urls
+        .get(name)
+        .or_else(|| {
+            urls.iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, url)| url)
+        })
+        .cloned()
+        .or_else(|| {
+            template.map(|template| {
+                template
+                    .replace("{name}", &percent_encode(name))
+                    .replace("{slug}", &slug(name))
+            })
+        })?;
+    if let Some(heading) = heading.filter(|heading| !heading.is_empty()) {
+        url.push('#');
+        url.push_str(&slug(heading));
+    }
+    Some(url)
+}
+
+/// `Deploy Notes (v2)` → `deploy-notes-v2`
+pub(crate) fn slug(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            out.push(c);
+        } else if !out.is_empty() && !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+    while out.ends_with('-') {
+        out.pop();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mapping_wins_over_template() {
+        let mut urls = HashMap::new();
+        urls.insert(
+            "Roadmap".to_string(),
+            "https://example.com/plan".to_string(),
+        );
+        assert_eq!(
+            resolve("roadmap", Some("https://x.test/{slug}"), &urls).as_deref(),
+            Some("https://example.com/plan")
+        );
+    }
+
+    #[test]
+    fn test_template_name_is_encoded() {
+        let urls = HashMap::new();
+        assert_eq!(
+            resolve("Q&A Notes", Some("https://wiki.test/?page={name}"), &urls).as_deref(),
+            Some("https://wiki.test/?page=Q%26A%20Notes")
+        );
+    }
+
+    #[test]
+    fn test_unresolved() {
+        let urls = HashMap::new();
+        assert_eq!(resolve("Anything", None, &urls), None);
+        assert_eq!(
+            resolve("#Local heading", Some("https://x.test/{slug}"), &urls),
+            None
+        );
+    }
+
+    #[test]
+    fn test_slug() {
+        assert_eq!(slug("Deploy Notes (v2)"), "deploy-notes-v2");
+        assert_eq!(slug("  Ünïcode Title "), "ünïcode-title");
+    }
+}
@@ -351,3 +351,172 @@ fn test_code_blocks_image_mode_creates_files() {
     std::fs::remove_file(&temp_path).ok();
     let _ = std::fs::remove_dir_all(&output_dir);
 }
+
+#[test]
+fn test_tables_flag() {
+    let input = "| a | b |\n|---|---|\n| 1 | 2 |";
+    let temp_path = temp_file("tables", "md");
+    std::fs::write(&temp_path, input).unwrap();
+
+    let output = Command::new("cargo")
+        .args(["run", "--"])
+        .arg(&temp_path)
+        .args(["--tables", "key-value"])
+        .output()
+        .expect("Failed to run");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("a: 1\nb: 2"));
+
+    std::fs::remove_file(&temp_path).ok();
+}
+
+#[test]
+fn test_unmapped_html_warning() {
+    let input = "<span>text</span> and <kbd>Esc</kbd>";
+    let temp_path = temp_file("html", "md");
+    std::fs::write(&temp_path, input).unwrap();
+
+    let output = Command::new("cargo")
+        .args(["run", "--"])
+        .arg(&temp_path)
+        .output()
+        .expect("Failed to run");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stdout.contains("text and 「Esc」"));
+    assert!(stderr.contains("Warning: HTML tags not converted: <span>"));
+
+    std::fs::remove_file(&temp_path).ok();
+}
+
+#[test]
+fn test_emoji_map_flag() {
+    let input = ":shipit: :rocket:";
+    let temp_path = temp_file("emoji", "md");
+    let map_path = temp_file("emoji_map", "txt");
+    std::fs::write(&temp_path, input).unwrap();
+    std::fs::write(&map_path, "# team emoji\nshipit = 🐿️\n").unwrap();
+
+    let output = Command::new("cargo")
+        .args(["run", "--"])
+        .arg(&temp_path)
+        .arg("--emoji-map")
+        .arg(&map_path)
+        .output()
+        .expect("Failed to run");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("🐿️ 🚀"));
+
+    std::fs::remove_file(&temp_path).ok();
+    std::fs::remove_file(&map_path).ok();
+}
+
+#[test]
+fn test_links_flag() {
+    let input = "[docs](https://example.com/docs)";
+    let temp_path = temp_file("links", "md");
+    std::fs::write(&temp_path, input).unwrap();
+
+    let output = Command::new("cargo")
+        .args(["run", "--"])
+        .arg(&temp_path)
+        .args(["--links", "domain"])
+        .output()
+        .expect("Failed to run");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("docs (example.com)"));
+
+    std::fs::remove_file(&temp_path).ok();
+}
+
+#[test]
+fn test_utm_flags() {
+    let input = "[site](https://example.com/?utm_source=old) and [repo](https://github.com/x)";
+    let temp_path = temp_file("utm", "md");
+    std::fs::write(&temp_path, input).unwrap();
+
+    let output = Command::new("cargo")
+        .args(["run", "--"])
+        .arg(&temp_path)
+        .args(["--utm", "source=linkedin", "--url-allow", "example.com"])
+        .output()
+        .expect("Failed to run");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("site (https://example.com/?utm_source=linkedin)"));
+    assert!(stdout.contains("repo (https://github.com/x)"));
+
+    std::fs::remove_file(&temp_path).ok();
+}
+
+#[test]
+fn test_json_preview_url() {
+    let input = "Launch notes: [read more](https://example.com/launch){.preview}";
+    let temp_path = temp_file("preview", "md");
+    std::fs::write(&temp_path, input).unwrap();
+
+    let output = Command::new("cargo")
+        .args(["run", "--"])
+        .arg(&temp_path)
+        .args(["--format", "json"])
+        .output()
+        .expect("Failed to run");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("read more\\n\\nhttps://example.com/launch"));
+    assert!(stdout.contains("\"preview_url\":\"https://example.com/launch\""));
+
+    std::fs::remove_file(&temp_path).ok();
+}
+
+#[test]
+fn test_first_comment_output() {
+    let input = "Big news!\n\n## First comment\n\nDetails: https://example.com";
+    let temp_path = temp_file("first_comment", "md");
+    std::fs::write(&temp_path, input).unwrap();
+
+    let output = Command::new("cargo")
+        .args(["run", "--"])
+        .arg(&temp_path)
+        .output()
+        .expect("Failed to run");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout
+        .contains("Big news!\n\n───── 💬 First comment ─────\n\nDetails: https://example.com"));
+
+    let output = Command::new("cargo")
+        .args(["run", "--"])
+        .arg(&temp_path)
+        .args(["--format", "json", "--comment-max-chars", "10"])
+        .output()
+        .expect("Failed to run");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stdout.contains("\"text\":\"Big news!\",\"char_count\":9"));
+    assert!(stdout.contains(
+        "\"first_comment\":{\"text\":\"Details: https://example.com\",\"char_count\":28,\"limit\":10,\"limit_exceeded\":true}"
+    ));
+    assert!(stderr.contains("Warning: First comment is 28 characters (limit: 10)"));
+
+    std::fs::remove_file(&temp_path).ok();
+}
+
+#[test]
+fn test_first_comment_with_empty_post() {
+    let temp_path = temp_file("first_comment_only", "md");
+    std::fs::write(&temp_path, "<!-- first-comment -->\n\nOnly a comment").unwrap();
+
+    let output = Command::new("cargo")
+        .args(["run", "--"])
+        .arg(&temp_path)
+        .output()
+        .expect("Failed to run");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("───── 💬 First comment ─────\n\nOnly a comment"));
+
+    std::fs::remove_file(&temp_path).ok();
+}